@@ -0,0 +1,169 @@
+//! Small helpers for animating values over time.
+//!
+//! [`Easing`](enum.Easing.html) and [`ease`](fn.ease.html) shape a progress value between 0 and 1,
+//! while [`Tween`](struct.Tween.html) uses them to move any [`Interpolate`](trait.Interpolate.html) value towards a target.
+//!
+//! Example usage:
+//! ```
+//! use glerminal::animation::{Easing, Tween};
+//!
+//! let mut tween = Tween::new(0.0, 10.0, 2.0).with_easing(Easing::EaseInOut);
+//!
+//! tween.update(1.0);
+//! assert_eq!(tween.get_value(), 5.0);
+//!
+//! tween.update(1.0);
+//! assert!(tween.is_finished());
+//! assert_eq!(tween.get_value(), 10.0);
+//! ```
+
+use crate::Color;
+
+/// Determines how the progress of an animation is shaped over time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Starts slow and speeds up
+    EaseIn,
+    /// Starts fast and slows down
+    EaseOut,
+    /// Starts slow, speeds up and slows down at the end
+    EaseInOut,
+}
+
+/// Apply the easing to `t`, which is clamped between 0 and 1.
+///
+/// Every easing returns 0 at t = 0, 1 at t = 1 and 0.5 at t = 0.5 except for `EaseIn` and `EaseOut`.
+pub fn ease(easing: Easing, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    match easing {
+        Easing::Linear => t,
+        Easing::EaseIn => t * t,
+        Easing::EaseOut => t * (2.0 - t),
+        Easing::EaseInOut => {
+            if t < 0.5 {
+                2.0 * t * t
+            } else {
+                -1.0 + (4.0 - 2.0 * t) * t
+            }
+        }
+    }
+}
+
+/// Represents a value that can be interpolated between two values.
+pub trait Interpolate: Clone {
+    /// Return the value between `self` and `other`, where `t` of 0 is `self` and 1 is `other`.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &f32, t: f32) -> f32 {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Color, t: f32) -> Color {
+        let mut color = *self;
+        for (idx, value) in color.iter_mut().enumerate() {
+            *value = value.interpolate(&other[idx], t);
+        }
+        color
+    }
+}
+
+impl Interpolate for (f32, f32) {
+    fn interpolate(&self, other: &(f32, f32), t: f32) -> (f32, f32) {
+        (
+            self.0.interpolate(&other.0, t),
+            self.1.interpolate(&other.1, t),
+        )
+    }
+}
+
+impl Interpolate for (u32, u32) {
+    fn interpolate(&self, other: &(u32, u32), t: f32) -> (u32, u32) {
+        (
+            (self.0 as f32).interpolate(&(other.0 as f32), t).round() as u32,
+            (self.1 as f32).interpolate(&(other.1 as f32), t).round() as u32,
+        )
+    }
+}
+
+/// Moves a value from one value to another over a duration (in seconds).
+///
+/// Call [`update`](#method.update) with the delta-time every frame and read the current value with [`get_value`](#method.get_value).
+#[derive(Clone, Debug)]
+pub struct Tween<T: Interpolate> {
+    from: T,
+    to: T,
+    duration: f32,
+    elapsed: f32,
+    easing: Easing,
+}
+
+impl<T: Interpolate> Tween<T> {
+    /// Create a new Tween that moves from `from` to `to` in `duration` seconds.
+    pub fn new(from: T, to: T, duration: f32) -> Tween<T> {
+        Tween {
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Set the easing of the Tween, default is Linear.
+    pub fn with_easing(mut self, easing: Easing) -> Tween<T> {
+        self.easing = easing;
+        self
+    }
+
+    /// Set the easing of the Tween, default is Linear.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    /// Advance the Tween by `delta` seconds.
+    pub fn update(&mut self, delta: f32) {
+        self.elapsed = (self.elapsed + delta).min(self.duration);
+    }
+
+    /// Start the Tween over from the beginning.
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// Start a new Tween from the current value to the given target.
+    pub fn retarget(&mut self, to: T) {
+        self.from = self.get_value();
+        self.to = to;
+        self.elapsed = 0.0;
+    }
+
+    /// Returns the progress of the Tween between 0 and 1, before easing.
+    pub fn get_progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            self.elapsed / self.duration
+        }
+    }
+
+    /// Returns whether the Tween has reached its target.
+    pub fn is_finished(&self) -> bool {
+        self.get_progress() >= 1.0
+    }
+
+    /// Returns the current value of the Tween.
+    pub fn get_value(&self) -> T {
+        if self.is_finished() {
+            self.to.clone()
+        } else {
+            self.from
+                .interpolate(&self.to, ease(self.easing, self.get_progress()))
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use gl;
 use glutin::{
-    ContextBuilder, ElementState, Event, EventsLoop, GlContext, GlRequest, GlWindow, WindowBuilder,
-    WindowEvent,
+    ContextBuilder, CursorState, ElementState, Event, EventsLoop, GlContext, GlRequest, GlWindow,
+    WindowBuilder, WindowEvent,
 };
 
-use crate::events::Events;
+use crate::events::{Events, TerminalEvent};
 use crate::renderer::{self, Matrix4};
+use crate::terminal::BlendMode;
 use crate::TextBuffer;
 use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
@@ -17,20 +18,40 @@ use glutin::VirtualKeyCode;
 pub struct TextBufferDisplayData {
     pub proj_matrix: Matrix4,
     pub aspect_ratio: f32,
+    pub grid: (u32, u32),
     pub overflows: (f32, f32),
     pub relative_dimensions: (f32, f32),
 }
 
 impl TextBufferDisplayData {
-    pub fn new(width: f32, height: f32, text_buffer: &TextBuffer) -> TextBufferDisplayData {
-        let (overflows, relative_dimensions) =
-            Display::calc_overflows_dimensions(width, height, text_buffer.aspect_ratio);
-        TextBufferDisplayData {
-            proj_matrix: renderer::create_proj_matrix((width, height), text_buffer.aspect_ratio),
+    pub fn new(
+        width: f32,
+        height: f32,
+        text_buffer: &TextBuffer,
+        snap_to_cells: bool,
+    ) -> TextBufferDisplayData {
+        let mut data = TextBufferDisplayData {
+            proj_matrix: [0.0; 16],
             aspect_ratio: text_buffer.aspect_ratio,
-            overflows: overflows,
-            relative_dimensions: relative_dimensions,
-        }
+            grid: (text_buffer.width, text_buffer.height),
+            overflows: (0.0, 0.0),
+            relative_dimensions: (1.0, 1.0),
+        };
+        data.update(width, height, snap_to_cells);
+        data
+    }
+
+    pub fn update(&mut self, width: f32, height: f32, snap_to_cells: bool) {
+        let area = if snap_to_cells {
+            Display::calc_snapped_area(width, height, self.aspect_ratio, self.grid)
+        } else {
+            Display::calc_area(width, height, self.aspect_ratio)
+        };
+        let (overflows, relative_dimensions) =
+            Display::calc_area_overflows_dimensions(width, height, area);
+        self.proj_matrix = renderer::create_area_proj_matrix((width, height), area);
+        self.overflows = overflows;
+        self.relative_dimensions = relative_dimensions;
     }
 }
 
@@ -43,17 +64,22 @@ pub struct Display {
     events_loop: RefCell<EventsLoop>,
     width: Cell<f32>,
     height: Cell<f32>,
+    snap_to_cells: bool,
+    vsync: bool,
+    cursor_visible: Cell<bool>,
+    cursor_grabbed: Cell<bool>,
 }
 
 impl Display {
-    pub fn new<T: Into<String>>(
+    pub fn try_new<T: Into<String>>(
         title: T,
         dimensions: (u32, u32),
         clear_color: (f32, f32, f32, f32),
         visibility: bool,
         text_buffer_aspect_ratio: bool,
         vsync: bool,
-    ) -> Display {
+        snap_to_cells: bool,
+    ) -> Result<Display, String> {
         let (width, height) = dimensions;
         let aspect_ratio = width as f32 / height as f32;
         let title = title.into();
@@ -65,29 +91,22 @@ impl Display {
         let context = ContextBuilder::new()
             .with_vsync(vsync)
             .with_gl(GlRequest::Latest);
-        let window = match GlWindow::new(window, context, &events_loop) {
-            Ok(window) => window,
-            Err(err) => panic!(err),
-        };
+        let window = GlWindow::new(window, context, &events_loop)
+            .map_err(|error| format!("Failed to create the window: {}", error))?;
 
         let width = width as f32;
         let height = height as f32;
 
         unsafe {
             let (r, g, b, a) = clear_color;
-            if let Err(err) = window.make_current() {
-                panic!(err);
-            }
+            window
+                .make_current()
+                .map_err(|error| format!("Failed to make the GL context current: {}", error))?;
             gl::load_with(|symbol| window.get_proc_address(symbol) as *const _);
             gl::ClearColor(r, g, b, a);
             gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
         };
-
-        let gl_version = renderer::get_version();
-        if !renderer::is_gl_version_compatible(&gl_version) {
-            panic!("GL version too low: OpenGL {}", gl_version);
-        }
+        renderer::set_blend_mode(BlendMode::Alpha);
 
         let proj_matrix = renderer::create_proj_matrix((width, height), aspect_ratio);
 
@@ -100,7 +119,7 @@ impl Display {
             HashMap::new(),
         );
 
-        Display {
+        Ok(Display {
             window: window,
             events: RefCell::new(events),
             events_loop: RefCell::new(events_loop),
@@ -109,52 +128,58 @@ impl Display {
             display_datas: RefCell::new(HashMap::new()),
             width: Cell::new(width),
             height: Cell::new(height),
-        }
+            snap_to_cells,
+            vsync,
+            cursor_visible: Cell::new(true),
+            cursor_grabbed: Cell::new(false),
+        })
+    }
+
+    pub fn present(&self) {
+        self.window.swap_buffers().ok();
     }
 
-    pub fn refresh(&self) -> bool {
+    pub fn process_events(&self) -> bool {
         let mut running = true;
 
         let mut dimensions: Option<(f32, f32)> = None;
 
         self.events.borrow_mut().clear_just_lists();
 
-        self.window.swap_buffers().ok();
-
         self.events_loop.borrow_mut().poll_events(|event| {
             if let Event::WindowEvent { event, .. } = event {
                 match event {
                     WindowEvent::CloseRequested => {
                         running = false;
+                        self.events.borrow_mut().push_event(TerminalEvent::Closed);
                     }
                     WindowEvent::Destroyed => {
                         running = false;
                     }
                     WindowEvent::Resized(width, height) => {
                         dimensions = Some((width as f32, height as f32));
+                        self.events.borrow_mut().update_resized((width, height));
                     }
                     WindowEvent::KeyboardInput { input, .. } => {
                         if let (state, Some(keycode)) = (input.state, input.virtual_keycode) {
                             self.events
                                 .borrow_mut()
-                                .keyboard
-                                .update_button_press(keycode, state == ElementState::Pressed);
+                                .update_key(keycode, state == ElementState::Pressed);
                         }
                     }
                     WindowEvent::MouseInput { button, state, .. } => self
                         .events
                         .borrow_mut()
-                        .mouse
-                        .update_button_press(button, state == ElementState::Pressed),
+                        .update_mouse_button(button, state == ElementState::Pressed),
                     WindowEvent::CursorMoved { position, .. } => {
-                        self.events.borrow_mut().cursor.update_location((
+                        self.events.borrow_mut().update_cursor_location((
                             position.0 as f32 / self.width.get(),
                             position.1 as f32 / self.height.get(),
                         ));
                     }
                     WindowEvent::CursorLeft { .. } => self.events.borrow_mut().cursor.cursor_left(),
                     WindowEvent::ReceivedCharacter(character) => {
-                        self.events.borrow_mut().chars.add_char(character);
+                        self.events.borrow_mut().add_char(character);
                     }
                     _ => (),
                 }
@@ -182,12 +207,77 @@ impl Display {
         self.window.show();
     }
 
+    pub fn get_dimensions(&self) -> (f32, f32) {
+        (self.width.get(), self.height.get())
+    }
+
+    pub fn get_overflows_dimensions(&self) -> ((f32, f32), (f32, f32)) {
+        Display::calc_overflows_dimensions(
+            self.width.get(),
+            self.height.get(),
+            self.aspect_ratio.get(),
+        )
+    }
+
+    pub fn set_resizable(&self, resizable: bool) {
+        self.window.set_resizable(resizable);
+    }
+
+    /// The swap interval can't be changed after the context is created,
+    /// so this only succeeds if vsync is already in the requested state
+    pub fn set_vsync(&self, vsync: bool) -> bool {
+        self.vsync == vsync
+    }
+
+    pub fn set_min_size(&self, dimensions: Option<(u32, u32)>) {
+        self.window.set_min_dimensions(dimensions);
+    }
+
+    pub fn set_max_size(&self, dimensions: Option<(u32, u32)>) {
+        self.window.set_max_dimensions(dimensions);
+    }
+
+    pub fn set_cursor_visible(&self, visible: bool) -> Result<(), String> {
+        self.apply_cursor_state(visible, self.cursor_grabbed.get())?;
+        self.cursor_visible.set(visible);
+        Ok(())
+    }
+
+    pub fn set_cursor_grab(&self, grab: bool) -> Result<(), String> {
+        self.apply_cursor_state(self.cursor_visible.get(), grab)?;
+        self.cursor_grabbed.set(grab);
+        Ok(())
+    }
+
+    pub fn set_cursor_position(&self, position: (i32, i32)) -> Result<(), String> {
+        self.window
+            .set_cursor_position(position.0, position.1)
+            .map_err(|_| "Failed to set the cursor position".to_owned())
+    }
+
+    /// Applies the cursor state to the window, without storing it, so a failed change leaves the stored state as is
+    fn apply_cursor_state(&self, visible: bool, grabbed: bool) -> Result<(), String> {
+        let state = if grabbed {
+            CursorState::Grab
+        } else if !visible {
+            CursorState::Hide
+        } else {
+            CursorState::Normal
+        };
+        self.window.set_cursor_state(state)
+    }
+
     pub(crate) fn get_display_data(&self, text_buffer: &TextBuffer) -> TextBufferDisplayData {
         let mut display_datas = self.display_datas.borrow_mut();
         if !display_datas.contains_key(&text_buffer.get_idx()) {
             display_datas.insert(
                 text_buffer.get_idx(),
-                TextBufferDisplayData::new(self.width.get(), self.height.get(), &text_buffer),
+                TextBufferDisplayData::new(
+                    self.width.get(),
+                    self.height.get(),
+                    &text_buffer,
+                    self.snap_to_cells,
+                ),
             );
 
             self.update_event_display_datas(display_datas.clone());
@@ -197,10 +287,7 @@ impl Display {
 
     #[cfg(test)]
     pub(crate) fn update_virtual_keycode(&mut self, keycode: VirtualKeyCode, pressed: bool) {
-        self.events
-            .borrow_mut()
-            .keyboard
-            .update_button_press(keycode, pressed);
+        self.events.borrow_mut().update_key(keycode, pressed);
     }
 
     fn update_view(&self) {
@@ -210,18 +297,7 @@ impl Display {
         ));
 
         for data in self.display_datas.borrow_mut().values_mut() {
-            data.proj_matrix = renderer::create_proj_matrix(
-                (self.width.get() as f32, self.height.get() as f32),
-                data.aspect_ratio,
-            );
-
-            let (overflows, relative_dimensions) = Display::calc_overflows_dimensions(
-                self.width.get(),
-                self.height.get(),
-                data.aspect_ratio,
-            );
-            data.overflows = overflows;
-            data.relative_dimensions = relative_dimensions;
+            data.update(self.width.get(), self.height.get(), self.snap_to_cells);
         }
 
         self.update_event_display_datas(self.display_datas.borrow().clone());
@@ -248,23 +324,52 @@ impl Display {
         height: f32,
         aspect_ratio: f32,
     ) -> ((f32, f32), (f32, f32)) {
+        Display::calc_area_overflows_dimensions(
+            width,
+            height,
+            Display::calc_area(width, height, aspect_ratio),
+        )
+    }
+
+    /// Calculates the largest area with the given aspect ratio that fits the window.
+    pub(crate) fn calc_area(width: f32, height: f32, aspect_ratio: f32) -> (f32, f32) {
         let true_width = height * aspect_ratio;
         let true_height = width / aspect_ratio;
-
-        let mut overflow_width = 0f32;
-        let mut overflow_height = 0f32;
-        let mut relative_width = 1.0;
-        let mut relative_height = 1.0;
         if true_width < width {
-            overflow_width = (width - true_width) / width;
-            relative_width = width / true_width;
+            (true_width, height)
         } else {
-            overflow_height = (height - true_height) / height;
-            relative_height = height / true_height;
+            (width, true_height)
         }
+    }
 
-        let overflows = (overflow_width / 2.0, overflow_height / 2.0);
-        let relative_dimensions = (relative_width, relative_height);
+    /// Calculates the area like `calc_area`, but rounded down so that every cell of the grid is a whole amount of pixels.
+    pub(crate) fn calc_snapped_area(
+        width: f32,
+        height: f32,
+        aspect_ratio: f32,
+        grid: (u32, u32),
+    ) -> (f32, f32) {
+        let (area_width, area_height) = Display::calc_area(width, height, aspect_ratio);
+        let (cols, rows) = (grid.0.max(1) as f32, grid.1.max(1) as f32);
+        let cell_width = (area_width / cols).floor();
+        let cell_height = (area_height / rows).floor();
+        if cell_width < 1.0 || cell_height < 1.0 {
+            (area_width, area_height)
+        } else {
+            (cell_width * cols, cell_height * rows)
+        }
+    }
+
+    fn calc_area_overflows_dimensions(
+        width: f32,
+        height: f32,
+        area: (f32, f32),
+    ) -> ((f32, f32), (f32, f32)) {
+        let overflows = (
+            (width - area.0) / width / 2.0,
+            (height - area.1) / height / 2.0,
+        );
+        let relative_dimensions = (width / area.0, height / area.1);
         (overflows, relative_dimensions)
     }
 }
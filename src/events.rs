@@ -3,6 +3,9 @@ use crate::TextBuffer;
 use glutin::{MouseButton, VirtualKeyCode};
 use std::collections::HashMap;
 
+// How many of the latest cursor positions are kept in the position history
+const POSITION_HISTORY_LENGTH: usize = 16;
+
 /// Represents all the events that happen in glerminal, such as keyboard events, mouse events, resize, and close events.
 ///
 /// ### Example usage:
@@ -34,6 +37,29 @@ pub struct Events {
     pub cursor: Cursor,
     /// Allows the gathering of unicode characters that the terminal received. Optimal for text receiving.
     pub chars: Chars,
+    terminal_events: Vec<TerminalEvent>,
+    resized: Option<(u32, u32)>,
+}
+
+/// A single discrete event that happened in the terminal, see [`Terminal::poll_events`](struct.Terminal.html#method.poll_events).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerminalEvent {
+    /// A keyboard key was pressed
+    KeyPressed(VirtualKeyCode),
+    /// A keyboard key was released
+    KeyReleased(VirtualKeyCode),
+    /// The cursor moved to the given location, relative to the window size (1.0 being the whole width or height)
+    MouseMoved((f32, f32)),
+    /// A mouse button was pressed
+    MousePressed(MouseButton),
+    /// A mouse button was released
+    MouseReleased(MouseButton),
+    /// A unicode character was received
+    Char(char),
+    /// The window was resized to the given width and height (in pixels)
+    Resized((u32, u32)),
+    /// The window was requested to close
+    Closed,
 }
 
 impl Events {
@@ -43,6 +69,8 @@ impl Events {
             mouse: Input::new(),
             cursor: Cursor::new(text_buffer_aspect_ratio),
             chars: Chars::new(),
+            terminal_events: Vec::new(),
+            resized: None,
         }
     }
 
@@ -51,6 +79,302 @@ impl Events {
         self.mouse.clear_just_lists();
         self.cursor.clear_just_moved();
         self.chars.clear_just_received();
+        self.terminal_events.clear();
+        self.resized = None;
+    }
+
+    pub(crate) fn update_key(&mut self, keycode: VirtualKeyCode, pressed: bool) {
+        if self.keyboard.update_button_press(keycode, pressed) {
+            self.terminal_events.push(if pressed {
+                TerminalEvent::KeyPressed(keycode)
+            } else {
+                TerminalEvent::KeyReleased(keycode)
+            });
+        }
+    }
+
+    pub(crate) fn update_mouse_button(&mut self, button: MouseButton, pressed: bool) {
+        if self.mouse.update_button_press(button, pressed) {
+            self.terminal_events.push(if pressed {
+                TerminalEvent::MousePressed(button)
+            } else {
+                TerminalEvent::MouseReleased(button)
+            });
+        }
+    }
+
+    pub(crate) fn update_cursor_location(&mut self, location: (f32, f32)) {
+        self.cursor.update_location(location);
+        self.terminal_events
+            .push(TerminalEvent::MouseMoved(location));
+    }
+
+    pub(crate) fn add_char(&mut self, character: char) {
+        self.chars.add_char(character);
+        self.terminal_events.push(TerminalEvent::Char(character));
+    }
+
+    pub(crate) fn update_resized(&mut self, size: (u32, u32)) {
+        self.resized = Some(size);
+        self.terminal_events.push(TerminalEvent::Resized(size));
+    }
+
+    pub(crate) fn push_event(&mut self, event: TerminalEvent) {
+        self.terminal_events.push(event);
+    }
+
+    /// Returns the new size of the window (in pixels), if it was resized this frame.
+    ///
+    /// Useful for knowing when to resize `TextBuffer`s.
+    pub fn resized(&self) -> Option<(u32, u32)> {
+        self.resized
+    }
+
+    /// Returns the discrete events that happened this frame, in the order they happened.
+    pub fn get_terminal_events(&self) -> Vec<TerminalEvent> {
+        self.terminal_events.clone()
+    }
+
+    /// Returns whether any keyboard key or mouse button was pressed this frame.
+    /// Useful for "press any key to continue" -screens.
+    pub fn any_just_pressed(&self) -> bool {
+        self.keyboard.any_just_pressed() || self.mouse.any_just_pressed()
+    }
+
+    /// Returns the characters received this frame collected into a String.
+    pub fn typed_text(&self) -> String {
+        self.chars.just_received_chars.iter().collect()
+    }
+}
+
+/// Maps a `VirtualKeyCode` to the character it would type on a US-QWERTY keyboard layout,
+/// with or without shift held. Returns `None` for keys that do not type a printable character.
+///
+/// Useful for displaying keybindings, or for games that read text straight from key presses.
+/// For actual text input, prefer [`Chars`](struct.Chars.html), which respects the user's keyboard layout.
+///
+/// Example:
+/// ```
+/// use glerminal::{keycode_to_char, VirtualKeyCode};
+///
+/// assert_eq!(keycode_to_char(VirtualKeyCode::A, false), Some('a'));
+/// assert_eq!(keycode_to_char(VirtualKeyCode::Key1, true), Some('!'));
+/// assert_eq!(keycode_to_char(VirtualKeyCode::Escape, false), None);
+/// ```
+pub fn keycode_to_char(key: VirtualKeyCode, shift: bool) -> Option<char> {
+    use glutin::VirtualKeyCode::*;
+
+    let (normal, shifted) = match key {
+        A => ('a', 'A'),
+        B => ('b', 'B'),
+        C => ('c', 'C'),
+        D => ('d', 'D'),
+        E => ('e', 'E'),
+        F => ('f', 'F'),
+        G => ('g', 'G'),
+        H => ('h', 'H'),
+        I => ('i', 'I'),
+        J => ('j', 'J'),
+        K => ('k', 'K'),
+        L => ('l', 'L'),
+        M => ('m', 'M'),
+        N => ('n', 'N'),
+        O => ('o', 'O'),
+        P => ('p', 'P'),
+        Q => ('q', 'Q'),
+        R => ('r', 'R'),
+        S => ('s', 'S'),
+        T => ('t', 'T'),
+        U => ('u', 'U'),
+        V => ('v', 'V'),
+        W => ('w', 'W'),
+        X => ('x', 'X'),
+        Y => ('y', 'Y'),
+        Z => ('z', 'Z'),
+        Key1 => ('1', '!'),
+        Key2 => ('2', '@'),
+        Key3 => ('3', '#'),
+        Key4 => ('4', '$'),
+        Key5 => ('5', '%'),
+        Key6 => ('6', '^'),
+        Key7 => ('7', '&'),
+        Key8 => ('8', '*'),
+        Key9 => ('9', '('),
+        Key0 => ('0', ')'),
+        Space => (' ', ' '),
+        Minus => ('-', '_'),
+        Equals => ('=', '+'),
+        LBracket => ('[', '{'),
+        RBracket => (']', '}'),
+        Backslash => ('\\', '|'),
+        Semicolon => (';', ':'),
+        Apostrophe => ('\'', '"'),
+        Grave => ('`', '~'),
+        Comma => (',', '<'),
+        Period => ('.', '>'),
+        Slash => ('/', '?'),
+        Numpad0 => ('0', '0'),
+        Numpad1 => ('1', '1'),
+        Numpad2 => ('2', '2'),
+        Numpad3 => ('3', '3'),
+        Numpad4 => ('4', '4'),
+        Numpad5 => ('5', '5'),
+        Numpad6 => ('6', '6'),
+        Numpad7 => ('7', '7'),
+        Numpad8 => ('8', '8'),
+        Numpad9 => ('9', '9'),
+        Add => ('+', '+'),
+        Subtract => ('-', '-'),
+        Multiply => ('*', '*'),
+        Divide => ('/', '/'),
+        Decimal => ('.', '.'),
+        _ => return None,
+    };
+
+    if shift {
+        Some(shifted)
+    } else {
+        Some(normal)
+    }
+}
+
+/// Returns a human-readable name for the given `VirtualKeyCode`, such as "Enter", "Left Arrow" or "Ctrl",
+/// useful for displaying key bindings. Keys that type a symbol are named after the symbol, ie. "-" for `Minus`.
+///
+/// Returns "Unknown" for uncommon keys (ie. media and browser keys) that have no name.
+///
+/// Example:
+/// ```
+/// use glerminal::{keycode_name, VirtualKeyCode};
+///
+/// assert_eq!(keycode_name(VirtualKeyCode::Return), "Enter");
+/// assert_eq!(keycode_name(VirtualKeyCode::Left), "Left Arrow");
+/// assert_eq!(keycode_name(VirtualKeyCode::LControl), "Ctrl");
+/// ```
+pub fn keycode_name(key: VirtualKeyCode) -> &'static str {
+    use glutin::VirtualKeyCode::*;
+
+    match key {
+        A => "A",
+        B => "B",
+        C => "C",
+        D => "D",
+        E => "E",
+        F => "F",
+        G => "G",
+        H => "H",
+        I => "I",
+        J => "J",
+        K => "K",
+        L => "L",
+        M => "M",
+        N => "N",
+        O => "O",
+        P => "P",
+        Q => "Q",
+        R => "R",
+        S => "S",
+        T => "T",
+        U => "U",
+        V => "V",
+        W => "W",
+        X => "X",
+        Y => "Y",
+        Z => "Z",
+        Key1 => "1",
+        Key2 => "2",
+        Key3 => "3",
+        Key4 => "4",
+        Key5 => "5",
+        Key6 => "6",
+        Key7 => "7",
+        Key8 => "8",
+        Key9 => "9",
+        Key0 => "0",
+        F1 => "F1",
+        F2 => "F2",
+        F3 => "F3",
+        F4 => "F4",
+        F5 => "F5",
+        F6 => "F6",
+        F7 => "F7",
+        F8 => "F8",
+        F9 => "F9",
+        F10 => "F10",
+        F11 => "F11",
+        F12 => "F12",
+        F13 => "F13",
+        F14 => "F14",
+        F15 => "F15",
+        Numpad0 => "Numpad 0",
+        Numpad1 => "Numpad 1",
+        Numpad2 => "Numpad 2",
+        Numpad3 => "Numpad 3",
+        Numpad4 => "Numpad 4",
+        Numpad5 => "Numpad 5",
+        Numpad6 => "Numpad 6",
+        Numpad7 => "Numpad 7",
+        Numpad8 => "Numpad 8",
+        Numpad9 => "Numpad 9",
+        Escape => "Esc",
+        Return => "Enter",
+        Back => "Backspace",
+        Tab => "Tab",
+        Space => "Space",
+        Insert => "Insert",
+        Delete => "Delete",
+        Home => "Home",
+        End => "End",
+        PageUp => "Page Up",
+        PageDown => "Page Down",
+        Left => "Left Arrow",
+        Right => "Right Arrow",
+        Up => "Up Arrow",
+        Down => "Down Arrow",
+        LControl => "Ctrl",
+        RControl => "Ctrl",
+        LShift => "Shift",
+        RShift => "Shift",
+        LAlt => "Alt",
+        RAlt => "Alt",
+        LWin => "Super",
+        RWin => "Super",
+        Apps => "Menu",
+        Capital => "Caps Lock",
+        Numlock => "Num Lock",
+        Scroll => "Scroll Lock",
+        Snapshot => "Print Screen",
+        Pause => "Pause",
+        NumpadEnter => "Numpad Enter",
+        NumpadEquals => "Numpad =",
+        NumpadComma => "Numpad ,",
+        Add => "Numpad +",
+        Subtract => "Numpad -",
+        Multiply => "Numpad *",
+        Divide => "Numpad /",
+        Decimal => "Numpad .",
+        Minus => "-",
+        Equals => "=",
+        LBracket => "[",
+        RBracket => "]",
+        Backslash => "\\",
+        Semicolon => ";",
+        Apostrophe => "'",
+        Grave => "`",
+        Comma => ",",
+        Period => ".",
+        Slash => "/",
+        Colon => ":",
+        At => "@",
+        Caret => "^",
+        Underline => "_",
+        Mute => "Mute",
+        VolumeDown => "Volume Down",
+        VolumeUp => "Volume Up",
+        PlayPause => "Play/Pause",
+        NextTrack => "Next Track",
+        PrevTrack => "Previous Track",
+        _ => "Unknown",
     }
 }
 
@@ -89,6 +413,8 @@ impl Chars {
 pub struct Cursor {
     location: Option<(f32, f32)>,
     just_moved: bool,
+    delta: (f32, f32),
+    position_history: Vec<(f32, f32)>,
     use_text_buffer_overflows: bool,
     display_overflows: (f32, f32),
     display_relative_dimensions: (f32, f32),
@@ -100,6 +426,8 @@ impl Cursor {
         Cursor {
             location: None,
             just_moved: false,
+            delta: (0.0, 0.0),
+            position_history: Vec::new(),
             use_text_buffer_overflows: use_text_buffer_overflows,
             display_overflows: (0.0, 0.0),
             display_relative_dimensions: (0.0, 0.0),
@@ -121,16 +449,27 @@ impl Cursor {
     pub(crate) fn update_location(&mut self, location: (f32, f32)) {
         self.just_moved = true;
 
+        if let Some(previous) = self.location {
+            self.delta.0 += location.0 - previous.0;
+            self.delta.1 += location.1 - previous.1;
+        }
         self.location = Some((location.0, location.1));
+
+        if self.position_history.len() >= POSITION_HISTORY_LENGTH {
+            self.position_history.remove(0);
+        }
+        self.position_history.push(location);
     }
 
     pub(crate) fn cursor_left(&mut self) {
         self.just_moved = true;
         self.location = None;
+        self.position_history.clear();
     }
 
     pub(crate) fn clear_just_moved(&mut self) {
         self.just_moved = false;
+        self.delta = (0.0, 0.0);
     }
 
     /// Weather the cursor has moved within the last frame
@@ -138,17 +477,23 @@ impl Cursor {
         self.just_moved
     }
 
+    /// Returns how much the cursor has moved within the last frame, relative to the window size (1.0 being the whole width or height).
+    pub fn get_delta(&self) -> (f32, f32) {
+        self.delta
+    }
+
+    /// Returns the latest positions of the cursor, oldest first, relative to the window size (1.0 being the whole width or height).
+    ///
+    /// A position is added every time the cursor moves, and at most the 16 latest positions are kept, across frames.
+    /// The history is cleared when the cursor leaves the window. Useful for detecting gestures, like swipes.
+    pub fn position_history(&self) -> &[(f32, f32)] {
+        &self.position_history
+    }
+
     /// Returns the current position of the cursor (the coordinates on the text buffer).
     pub fn get_location(&self, text_buffer: &TextBuffer) -> Option<(u32, u32)> {
         if let Some(location) = self.location {
-            let mut overflows = self.display_overflows;
-            let mut relative_dimensions = self.display_relative_dimensions;
-            if self.use_text_buffer_overflows {
-                if let Some(data) = self.text_buffer_datas.get(&text_buffer.get_idx()) {
-                    overflows = data.overflows;
-                    relative_dimensions = data.relative_dimensions;
-                }
-            }
+            let (overflows, relative_dimensions) = self.overflows_dimensions(text_buffer.get_idx());
 
             if location.0 > overflows.0
                 && location.0 < 1.0 - overflows.0
@@ -169,6 +514,47 @@ impl Cursor {
             None
         }
     }
+
+    /// Returns the current position of the cursor on the text buffer with the given index, without needing a reference to the text buffer.
+    ///
+    /// Unlike [`get_location`](#method.get_location), the position is returned even if the cursor is outside of the text buffer,
+    /// in which case the coordinates can be negative or past the dimensions of the text buffer.
+    /// Returns None if the cursor is not in the window, or if the text buffer has not been drawn yet.
+    pub fn get_location_for_idx(&self, idx: u32) -> Option<(i32, i32)> {
+        let location = self.location?;
+        let (width, height) = self.text_buffer_datas.get(&idx)?.grid;
+        let (overflows, relative_dimensions) = self.overflows_dimensions(idx);
+
+        let x = (location.0 - overflows.0) * relative_dimensions.0;
+        let y = (location.1 - overflows.1) * relative_dimensions.1;
+        Some((
+            (x * width as f32).floor() as i32,
+            (y * height as f32).floor() as i32,
+        ))
+    }
+
+    /// Returns whether the cursor is over the given rectangle (x, y, width, height) of characters in the text buffer.
+    pub fn is_over(&self, text_buffer: &TextBuffer, rect_cells: (u32, u32, u32, u32)) -> bool {
+        let (rect_x, rect_y, width, height) = rect_cells;
+        match self.get_location(text_buffer) {
+            Some((x, y)) => {
+                x >= rect_x
+                    && x < rect_x.saturating_add(width)
+                    && y >= rect_y
+                    && y < rect_y.saturating_add(height)
+            }
+            None => false,
+        }
+    }
+
+    fn overflows_dimensions(&self, idx: u32) -> ((f32, f32), (f32, f32)) {
+        match self.text_buffer_datas.get(&idx) {
+            Some(data) if self.use_text_buffer_overflows => {
+                (data.overflows, data.relative_dimensions)
+            }
+            _ => (self.display_overflows, self.display_relative_dimensions),
+        }
+    }
 }
 
 /// Input contains the necessary infoamtions to satisfy all your binary input-gathering needs!
@@ -196,15 +582,20 @@ impl<T: PartialEq + Copy> Input<T> {
         self.just_released.clear();
     }
 
-    pub(crate) fn update_button_press(&mut self, button: T, pressed: bool) {
+    /// Returns whether the state of the button changed
+    pub(crate) fn update_button_press(&mut self, button: T, pressed: bool) -> bool {
         if pressed && !self.pressed.contains(&button) {
             self.pressed.push(button);
             self.just_pressed.push(button);
+            true
         } else if !pressed && self.pressed.contains(&button) {
             self.just_released.push(button);
             if let Some(idx) = self.find_buttonpress_idx_from_pressed(button) {
                 self.pressed.remove(idx);
             }
+            true
+        } else {
+            false
         }
     }
 
@@ -223,6 +614,38 @@ impl<T: PartialEq + Copy> Input<T> {
         self.just_released.contains(&button)
     }
 
+    /// Returns wether any button was pressed this frame.
+    pub fn any_just_pressed(&self) -> bool {
+        !self.just_pressed.is_empty()
+    }
+
+    /// Returns wether a chord of buttons was pressed this frame, meaning the last button of `buttons` was just pressed
+    /// and the rest are held. Returns false if `buttons` is empty.
+    ///
+    /// Example:
+    /// ```
+    /// # use glerminal::{Events, VirtualKeyCode};
+    /// # fn save() {}
+    /// # fn check(events: &Events) {
+    /// if events.keyboard.chord_just_pressed(&[VirtualKeyCode::LControl, VirtualKeyCode::S]) {
+    ///     save();
+    /// }
+    /// # }
+    /// ```
+    pub fn chord_just_pressed(&self, buttons: &[T]) -> bool {
+        match buttons.split_last() {
+            Some((last, held)) => {
+                self.was_just_pressed(*last) && held.iter().all(|button| self.is_pressed(*button))
+            }
+            None => false,
+        }
+    }
+
+    /// Returns an iterator over the buttons that were just pressed, without cloning the list.
+    pub fn just_pressed_iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.just_pressed.iter().cloned()
+    }
+
     /// Returns an entire list of buttons that were just pressed.
     pub fn get_just_pressed_list(&self) -> Vec<T> {
         self.just_pressed.clone()
@@ -1,7 +1,7 @@
-use png::{ColorType, Decoder};
-use std::collections::HashMap;
+use png::{BitDepth, ColorType, Decoder, Encoder, HasParameters};
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
-use std::io::Read;
+use std::io::{BufWriter, Read};
 use std::path::PathBuf;
 
 use crate::FontFormat;
@@ -19,6 +19,7 @@ pub struct CharacterData {
     pub(crate) height: u32,
     pub(crate) x_off: i32,
     pub(crate) y_off: i32,
+    pub(crate) x_advance: i32,
 }
 
 /// The Font is used to load fonts that can be used in the [`TextBuffer`](text_buffer/struct.TextBuffer.html)
@@ -66,6 +67,22 @@ pub struct Font {
 }
 
 impl Font {
+    /// Creates a font without any characters, used as the default font when the font is not bundled
+    #[cfg(not(feature = "bundled_font"))]
+    pub(crate) fn empty() -> Font {
+        Font {
+            name: String::new(),
+            image_buffer: Vec::new(),
+            width: 0,
+            height: 0,
+            line_height: 0,
+            size: 0,
+            min_offset_y: 0,
+            average_xadvance: 0.0,
+            characters: HashMap::new(),
+        }
+    }
+
     /// Loads the font fron the given font file, for example:
     ///
     /// ```
@@ -86,7 +103,6 @@ impl Font {
 
         // Load Font image file
         let image_path = &bm_font.pages[0].image_path;
-        println!("{:?}", image_path);
         Font::load_with_bmfont_and_image_read(&bm_font, File::open(image_path).unwrap())
     }
 
@@ -156,6 +172,7 @@ impl Font {
                     height: value.height,
                     x_off: value.xoffset,
                     y_off: value.yoffset,
+                    x_advance: value.xadvance,
                 },
             );
         }
@@ -174,6 +191,33 @@ impl Font {
             characters: characters,
         }
     }
+
+    /// Returns the line height of the Font, same as the `line_height` field.
+    pub fn line_height(&self) -> u32 {
+        self.line_height
+    }
+
+    /// Returns the size (width) of the Font, same as the `size` field.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Returns the average horizontal advance of the characters in the Font,
+    /// which is used as the width of a single character in the `TextBuffer`.
+    pub fn average_xadvance(&self) -> f32 {
+        self.average_xadvance
+    }
+
+    /// Returns the characters whose horizontal advance is roughly twice the average or more,
+    /// which take up two cells in the `TextBuffer` (ie. CJK characters).
+    pub(crate) fn wide_characters(&self) -> HashSet<u16> {
+        self.characters
+            .iter()
+            .filter(|(_, data)| data.x_advance as f32 >= self.average_xadvance * 1.5)
+            .map(|(character, _)| *character)
+            .collect()
+    }
+
     /// Gets the CharacterData from the Font with the given char, if the charcter exists, otherwise returns an error as a String. Example:
     ///
     /// ```
@@ -187,4 +231,47 @@ impl Font {
             Err(format!("Character not found: '{}'", character))
         }
     }
+
+    /// Writes the glyph atlas of the Font into a PNG file at the given path.
+    /// Useful for debugging misaligned glyphs in custom fonts, together with [`debug_character`](#method.debug_character).
+    ///
+    /// Returns an error if the file could not be created or written.
+    pub fn dump_atlas<P: Into<PathBuf>>(&self, path: P) -> Result<(), String> {
+        let path = path.into();
+        let file = File::create(&path)
+            .map_err(|error| format!("Failed to create {}: {}", path.display(), error))?;
+
+        let mut encoder = Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set(ColorType::RGBA).set(BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.image_buffer))
+            .map_err(|error| format!("Failed to write {}: {}", path.display(), error))
+    }
+
+    /// Returns a human-readable description of the given character's UV rectangle, size and offsets,
+    /// or None if the character does not exist in the Font. Example:
+    ///
+    /// ```
+    /// use glerminal::{Font, FontFormat};
+    /// let font = Font::load(&FontFormat::SFL, "fonts/source_code_pro.sfl");
+    /// println!("{}", font.debug_character('a' as u16).unwrap());
+    /// ```
+    pub fn debug_character(&self, character: u16) -> Option<String> {
+        self.characters.get(&character).map(|data| {
+            format!(
+                "'{}' (id {}): uv ({}, {}) - ({}, {}), size {}x{}, offset ({}, {})",
+                String::from_utf16_lossy(&[character]),
+                data.id,
+                data.x1,
+                data.y1,
+                data.x2,
+                data.y2,
+                data.width,
+                data.height,
+                data.x_off,
+                data.y_off
+            )
+        })
+    }
 }
@@ -58,12 +58,14 @@
 //! Examples can be found at [`TextBuffer`](struct.TextBuffer.html) struct.
 //!
 //! # Features
-//! There are two features for Glerminal that can be enabled (or disabled) to enable or disable wanted features.
-//!
-//! | Feature name             | What it enables                                             |
-//! |--------------------------|-------------------------------------------------------------|
-//! | parser _(on by default)_ | enables the [Praser](struct.Parser.html) struct.            |
-//! | menu_systems             | enables the [menu_systems](menu_systems/index.html) module. |
+//! There are four features for Glerminal that can be enabled (or disabled) to enable or disable wanted features.
+//!
+//! | Feature name                   | What it enables                                                                              |
+//! |--------------------------------|----------------------------------------------------------------------------------------------|
+//! | parser _(on by default)_       | enables the [Praser](struct.Parser.html) struct.                                             |
+//! | menu_systems                   | enables the [menu_systems](menu_systems/index.html) module.                                  |
+//! | serde                          | enables serde `Serialize` and `Deserialize` for `TextStyle` and `TermCharacter`.             |
+//! | bundled_font _(on by default)_ | bundles the default font, without it a font must be given with `TerminalBuilder::with_font`. |
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 #![allow(clippy::float_cmp)]
@@ -78,15 +80,18 @@ mod renderer;
 mod terminal;
 mod text_buffer;
 
-pub use crate::events::{Cursor, Events, Input};
+pub use crate::events::{keycode_name, keycode_to_char, Cursor, Events, Input, TerminalEvent};
 pub use crate::font::{CharacterData, Font};
-pub use crate::terminal::{Terminal, TerminalBuilder};
+pub use crate::terminal::{BlendMode, FrameStats, Terminal, TerminalBuilder};
 pub use crate::text_buffer::text_processing;
-pub use crate::text_buffer::{Color, TermCharacter, TermCursor, TermLimits, TextBuffer, TextStyle};
+pub use crate::text_buffer::{
+    BorderChars, Color, TermCharacter, TermCursor, TermLimits, TextBuffer, TextStyle,
+};
 
 #[cfg(feature = "parser")]
 pub use crate::text_buffer::parser::Parser;
 
+pub mod animation;
 #[cfg(feature = "menu_systems")]
 pub mod menu_systems;
 
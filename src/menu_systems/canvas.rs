@@ -0,0 +1,118 @@
+use super::{InterfaceItem, InterfaceItemBase};
+use crate::text_processing::TextProcessor;
+use crate::{Events, TextBuffer};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type DrawFn = Rc<RefCell<dyn FnMut(&mut TextBuffer, (u32, u32))>>;
+type EventsFn = Rc<RefCell<dyn FnMut(&Events) -> bool>>;
+
+#[derive(Clone)]
+/// Represents an area of fixed size inside a Menu, where the drawing is done by a user-supplied closure.
+///
+/// The closure receives the `TextBuffer` and the position assigned to the Canvas, and is called every time the Canvas is drawn.
+///
+/// Note that cloning a Canvas does not clone the closures, the clones share the same closures instead.
+/// Menus clone their items when drawing, so any state captured by the closure is shared between
+/// the Canvas given to the Menu and the one actually drawn.
+///
+/// Example:
+/// ```
+/// use glerminal::menu_systems::Canvas;
+///
+/// Canvas::new(3, 2, |text_buffer, (x, y)| {
+///     text_buffer.cursor.move_to(x, y);
+///     text_buffer.write("abc");
+///     text_buffer.cursor.move_to(x, y + 1);
+///     text_buffer.write("def");
+/// });
+/// ```
+pub struct Canvas {
+    base: InterfaceItemBase,
+    width: u32,
+    height: u32,
+
+    draw_fn: DrawFn,
+    events_fn: Option<EventsFn>,
+}
+
+impl Canvas {
+    /// Initializes a new Canvas with the given size and draw closure
+    pub fn new<F: FnMut(&mut TextBuffer, (u32, u32)) + 'static>(
+        width: u32,
+        height: u32,
+        draw_fn: F,
+    ) -> Canvas {
+        Canvas {
+            base: InterfaceItemBase::new(false),
+            width,
+            height,
+
+            draw_fn: Rc::new(RefCell::new(draw_fn)),
+            events_fn: None,
+        }
+    }
+
+    with_base!(Canvas);
+
+    /// Sets the closure that handles events for this Canvas.
+    ///
+    /// The closure should return true if it used the events, like with [`InterfaceItem::handle_events`](trait.InterfaceItem.html#tymethod.handle_events).
+    /// Setting an events handler also makes the Canvas focusable.
+    pub fn with_events_handler<F: FnMut(&Events) -> bool + 'static>(
+        mut self,
+        events_fn: F,
+    ) -> Canvas {
+        self.set_events_handler(events_fn);
+        self
+    }
+
+    /// Sets the closure that handles events for this Canvas.
+    ///
+    /// The closure should return true if it used the events, like with [`InterfaceItem::handle_events`](trait.InterfaceItem.html#tymethod.handle_events).
+    /// Setting an events handler also makes the Canvas focusable.
+    pub fn set_events_handler<F: FnMut(&Events) -> bool + 'static>(&mut self, events_fn: F) {
+        self.events_fn = Some(Rc::new(RefCell::new(events_fn)));
+        self.base.can_be_focused = true;
+    }
+
+    /// Sets the size of the Canvas
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        self.base.dirty = true;
+    }
+}
+
+impl InterfaceItem for Canvas {
+    fn get_base(&self) -> &InterfaceItemBase {
+        &self.base
+    }
+
+    fn get_mut_base(&mut self) -> &mut InterfaceItemBase {
+        &mut self.base
+    }
+
+    fn get_total_width(&self) -> u32 {
+        self.width
+    }
+
+    fn get_total_height(&self) -> u32 {
+        self.height
+    }
+
+    fn draw(&mut self, text_buffer: &mut TextBuffer) {
+        self.base.dirty = false;
+        (self.draw_fn.borrow_mut())(text_buffer, self.base.get_pos());
+    }
+
+    fn handle_events(&mut self, events: &Events) -> bool {
+        match self.events_fn {
+            Some(ref events_fn) => (events_fn.borrow_mut())(events),
+            None => false,
+        }
+    }
+
+    fn update(&mut self, _: f32, _: &dyn TextProcessor) {}
+}
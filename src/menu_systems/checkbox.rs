@@ -1,6 +1,6 @@
 use std::iter::repeat;
 
-use super::{InterfaceItem, InterfaceItemBase};
+use super::{InterfaceItem, InterfaceItemBase, Theme};
 use crate::text_processing::{ProcessedChar, TextProcessor};
 use crate::{Events, MouseButton, TextBuffer, TextStyle, VirtualKeyCode};
 
@@ -14,6 +14,7 @@ use crate::{Events, MouseButton, TextBuffer, TextStyle, VirtualKeyCode};
 pub struct CheckboxGroup {
     selected_idx: Option<u32>,
     force_one_checked: Option<u32>,
+    selection_just_changed: bool,
 }
 
 impl CheckboxGroup {
@@ -22,6 +23,7 @@ impl CheckboxGroup {
         CheckboxGroup {
             selected_idx: None,
             force_one_checked: None,
+            selection_just_changed: false,
         }
     }
 
@@ -35,6 +37,7 @@ impl CheckboxGroup {
 
     /// Update this CheckboxGroup with the given Checkboxes, this will ensure that only one of the given Checkboxes will remain checked.
     pub fn update(&mut self, checkboxes: &mut [&mut Checkbox]) {
+        let previous_idx = self.selected_idx;
         let mut selection_changed = false;
         let mut any_selected = false;
         for idx in 0..checkboxes.len() {
@@ -87,12 +90,18 @@ impl CheckboxGroup {
                 self.selected_idx = None;
             }
         }
+        self.selection_just_changed = self.selected_idx != previous_idx;
     }
 
     /// Return the index that is currently selected, if any.
     pub fn get_selection_idx(&self) -> Option<u32> {
         self.selected_idx
     }
+
+    /// Returns whether the selected index changed during the last `update`.
+    pub fn selection_just_changed(&self) -> bool {
+        self.selection_just_changed
+    }
 }
 
 /// Represents a Checkbox that can be checked or unchecked, and it's checked-status can be get with `is_checked`.
@@ -171,9 +180,7 @@ impl Checkbox {
         }
     }
 
-    with_base!(Checkbox);
-    with_set_pressable!(Checkbox);
-    with_style!(Checkbox);
+    with_interface_item!(Checkbox);
 
     /// Sets the initial text of the Checkbox
     pub fn with_text<T: Into<String>>(mut self, text: T) -> Checkbox {
@@ -328,4 +335,12 @@ impl InterfaceItem for Checkbox {
             self.needs_processing = false;
         }
     }
+
+    fn apply_theme(&mut self, theme: &Theme) {
+        if self.unfocused_style != theme.text || self.focused_style != theme.focused {
+            self.unfocused_style = theme.text;
+            self.focused_style = theme.focused;
+            self.base.dirty = true;
+        }
+    }
 }
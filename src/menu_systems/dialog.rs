@@ -1,6 +1,6 @@
-use glutin::VirtualKeyCode;
+use glutin::{MouseButton, VirtualKeyCode};
 
-use super::{InterfaceItem, InterfaceItemBase};
+use super::{InterfaceItem, InterfaceItemBase, Theme};
 
 use crate::text_processing::{OptTextStyle, ProcessedChar, TextProcessor};
 use crate::{Events, TextBuffer, TextStyle};
@@ -34,6 +34,18 @@ pub struct Dialog {
     pub up_buttons: Vec<VirtualKeyCode>,
     /// The buttons that make the dialog scroll down when focused
     pub down_buttons: Vec<VirtualKeyCode>,
+    /// The buttons that make the dialog scroll up by a whole page when focused
+    pub page_up_buttons: Vec<VirtualKeyCode>,
+    /// The buttons that make the dialog scroll down by a whole page when focused
+    pub page_down_buttons: Vec<VirtualKeyCode>,
+    /// The buttons that make the dialog scroll to the top when focused
+    pub home_buttons: Vec<VirtualKeyCode>,
+    /// The buttons that make the dialog scroll to the last page when focused
+    pub end_buttons: Vec<VirtualKeyCode>,
+    /// The characters used for the scrollbar's track and thumb, default is `('│', '█')`.
+    ///
+    /// **Note:** The characters must exist in the font being used.
+    pub scrollbar_chars: (char, char),
 
     base: InterfaceItemBase,
     width: u32,
@@ -46,6 +58,17 @@ pub struct Dialog {
 
     processed_text: Vec<ProcessedChar>,
     needs_processing: bool,
+    needs_reflow: bool,
+
+    line_numbers: bool,
+    // Width of the line number gutter, including the separating space
+    gutter_width: u32,
+
+    // The highlighted query and it's style
+    highlight: Option<(Vec<char>, TextStyle)>,
+
+    scrollbar: bool,
+    dragging_scrollbar: bool,
 
     scroll_idx: u32,
 }
@@ -70,6 +93,11 @@ impl Dialog {
             },
             up_buttons: vec![VirtualKeyCode::Up],
             down_buttons: vec![VirtualKeyCode::Down],
+            page_up_buttons: vec![VirtualKeyCode::PageUp],
+            page_down_buttons: vec![VirtualKeyCode::PageDown],
+            home_buttons: vec![VirtualKeyCode::Home],
+            end_buttons: vec![VirtualKeyCode::End],
+            scrollbar_chars: ('│', '█'),
 
             base: InterfaceItemBase::new(true),
             width: width,
@@ -82,6 +110,15 @@ impl Dialog {
 
             processed_text: Vec::new(),
             needs_processing: true,
+            needs_reflow: false,
+
+            line_numbers: false,
+            gutter_width: 0,
+
+            highlight: None,
+
+            scrollbar: false,
+            dragging_scrollbar: false,
 
             scroll_idx: 0,
         }
@@ -93,7 +130,7 @@ impl Dialog {
     /// Sets the initial width of the dialog window
     pub fn with_width(mut self, width: u32) -> Dialog {
         self.width = width;
-        self.needs_processing = true;
+        self.needs_reflow = true;
         self
     }
 
@@ -116,6 +153,22 @@ impl Dialog {
         self
     }
 
+    /// Sets whether the row numbers are shown in a gutter on the left side of the dialog.
+    ///
+    /// The gutter is as wide as the largest row number (plus a space), and the text is wrapped to the remaining width.
+    pub fn with_line_numbers(mut self, line_numbers: bool) -> Dialog {
+        self.set_line_numbers(line_numbers);
+        self
+    }
+
+    /// Sets whether a scrollbar is shown on the right edge of the dialog, reserving one column from the text.
+    ///
+    /// The scrollbar can be used with the mouse with [`drag_scrollbar`](#method.drag_scrollbar).
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Dialog {
+        self.set_scrollbar(scrollbar);
+        self
+    }
+
     /// Set the initial buttons that scroll the dialog up.
     pub fn with_up_buttons(mut self, buttons: Vec<VirtualKeyCode>) -> Dialog {
         self.up_buttons = buttons;
@@ -128,10 +181,131 @@ impl Dialog {
         self
     }
 
-    /// Sets the width of the dialog window
+    /// Set the initial buttons that scroll the dialog up by a whole page.
+    pub fn with_page_up_buttons(mut self, buttons: Vec<VirtualKeyCode>) -> Dialog {
+        self.page_up_buttons = buttons;
+        self
+    }
+
+    /// Set the initial buttons that scroll the dialog down by a whole page.
+    pub fn with_page_down_buttons(mut self, buttons: Vec<VirtualKeyCode>) -> Dialog {
+        self.page_down_buttons = buttons;
+        self
+    }
+
+    /// Set the initial buttons that scroll the dialog to the top.
+    pub fn with_home_buttons(mut self, buttons: Vec<VirtualKeyCode>) -> Dialog {
+        self.home_buttons = buttons;
+        self
+    }
+
+    /// Set the initial buttons that scroll the dialog to the last page.
+    pub fn with_end_buttons(mut self, buttons: Vec<VirtualKeyCode>) -> Dialog {
+        self.end_buttons = buttons;
+        self
+    }
+
+    /// Sets the width of the dialog window.
+    ///
+    /// The rows are recalculated on the next `update`, or immediately with [`reflow`](#method.reflow).
     pub fn set_width(&mut self, width: u32) {
         self.width = width;
-        self.needs_processing = true;
+        self.needs_reflow = true;
+    }
+
+    /// Immediately recalculates the rows of the dialog for the current width, without processing the text again.
+    ///
+    /// Useful when the width is changed between frames. If the text was changed after the last `update`,
+    /// the previous text is used until the next `update`.
+    pub fn reflow(&mut self) {
+        self.update_rows();
+        self.needs_reflow = false;
+        self.scroll_idx = self
+            .scroll_idx
+            .min((self.rows.len() as u32).saturating_sub(1));
+        self.base.dirty = true;
+    }
+
+    /// Sets whether the row numbers are shown in a gutter on the left side of the dialog.
+    ///
+    /// The rows are recalculated on the next `update`, or immediately with [`reflow`](#method.reflow).
+    pub fn set_line_numbers(&mut self, line_numbers: bool) {
+        self.line_numbers = line_numbers;
+        self.needs_reflow = true;
+    }
+
+    /// Sets whether a scrollbar is shown on the right edge of the dialog (see [`with_scrollbar`](#method.with_scrollbar)).
+    ///
+    /// The rows are recalculated on the next `update`, or immediately with [`reflow`](#method.reflow).
+    pub fn set_scrollbar(&mut self, scrollbar: bool) {
+        self.scrollbar = scrollbar;
+        self.dragging_scrollbar = false;
+        self.needs_reflow = true;
+    }
+
+    /// Scrolls the dialog with the mouse, if the scrollbar is enabled. Returns whether the scroll changed.
+    ///
+    /// Pressing the left mouse button on the scrollbar starts dragging it, and while the button is held,
+    /// the dialog is scrolled in proportion to the cursor's position along the scrollbar.
+    /// Should be called every frame, for example after updating the menu:
+    /// ```no_run
+    /// # use glerminal::{TerminalBuilder, TextBuffer};
+    /// # use glerminal::menu_systems::Dialog;
+    /// # let terminal = TerminalBuilder::new().build();
+    /// # let text_buffer = TextBuffer::create(&terminal, (80, 24)).unwrap();
+    /// let mut dialog = Dialog::new(20, 5, 5).with_scrollbar(true);
+    /// // ...
+    /// let events = terminal.get_current_events();
+    /// dialog.drag_scrollbar(&text_buffer, &events);
+    /// ```
+    pub fn drag_scrollbar(&mut self, text_buffer: &TextBuffer, events: &Events) -> bool {
+        let height = self.get_total_height();
+        if !self.scrollbar || height == 0 {
+            return false;
+        }
+        let location = events.cursor.get_location(text_buffer);
+        if events.mouse.was_just_pressed(MouseButton::Left) {
+            let bar_x = self.base.x + self.width.saturating_sub(1);
+            self.dragging_scrollbar = match location {
+                Some((x, y)) => x == bar_x && y >= self.base.y && y < self.base.y + height,
+                None => false,
+            };
+        } else if !events.mouse.is_pressed(MouseButton::Left) {
+            self.dragging_scrollbar = false;
+        }
+
+        match location {
+            Some((_, y)) if self.dragging_scrollbar => {
+                let track_y = y.saturating_sub(self.base.y).min(height - 1);
+                let scroll = match height {
+                    1 => 0,
+                    _ => (track_y * self.last_page_scroll() + (height - 1) / 2) / (height - 1),
+                };
+                self.scroll_to(scroll)
+            }
+            _ => false,
+        }
+    }
+
+    /// Highlights every occurrence of `query` in the text with the given style, replacing any previous highlight.
+    /// An empty query clears the highlight.
+    ///
+    /// The matching is case-sensitive and done on the processed text, so any tags of the Parser are not matched.
+    /// Only matches within a single row are highlighted.
+    /// The rows are recalculated on the next `update`, or immediately with [`reflow`](#method.reflow).
+    pub fn highlight(&mut self, query: &str, style: TextStyle) {
+        self.highlight = if query.is_empty() {
+            None
+        } else {
+            Some((query.chars().collect(), style))
+        };
+        self.needs_reflow = true;
+    }
+
+    /// Clears the highlight set with [`highlight`](#method.highlight).
+    pub fn clear_highlight(&mut self) {
+        self.highlight = None;
+        self.needs_reflow = true;
     }
 
     /// Sets the minimum height of the dialog window
@@ -172,6 +346,33 @@ impl Dialog {
         }
     }
 
+    /// Attempt to scroll the dialog up by a whole page (the height of the dialog). Returns true if successful, false if not.
+    pub fn page_up(&mut self) -> bool {
+        let scroll = self.scroll_idx.saturating_sub(self.get_total_height());
+        self.scroll_to(scroll)
+    }
+
+    /// Attempt to scroll the dialog down by a whole page (the height of the dialog), stopping at the last page.
+    /// Returns true if successful, false if not.
+    pub fn page_down(&mut self) -> bool {
+        let scroll = (self.scroll_idx + self.get_total_height())
+            .min(self.last_page_scroll())
+            .max(self.scroll_idx);
+        self.scroll_to(scroll)
+    }
+
+    /// Attempt to scroll the dialog to the top. Returns true if successful, false if not.
+    pub fn scroll_to_top(&mut self) -> bool {
+        self.scroll_to(0)
+    }
+
+    /// Attempt to scroll the dialog to the last page, so that the last row is at the bottom of the dialog.
+    /// Returns true if successful, false if not.
+    pub fn scroll_to_bottom(&mut self) -> bool {
+        let scroll = self.last_page_scroll();
+        self.scroll_to(scroll)
+    }
+
     /// Get the current scroll idx of this dialog
     pub fn get_scroll(&self) -> u32 {
         self.scroll_idx
@@ -184,9 +385,84 @@ impl Dialog {
         }
     }
 
+    /// Returns the position and length of the scrollbar's thumb, relative to the top of the dialog
+    fn scrollbar_thumb(&self) -> (u32, u32) {
+        let height = self.get_total_height();
+        let rows = self.rows.len() as u32;
+        if rows <= height || height == 0 {
+            return (0, height);
+        }
+        let length = ((height * height + rows / 2) / rows).max(1);
+        let max_scroll = self.last_page_scroll();
+        let scroll = self.scroll_idx.min(max_scroll);
+        let pos = (scroll * (height - length) + max_scroll / 2) / max_scroll;
+        (pos, length)
+    }
+
+    fn last_page_scroll(&self) -> u32 {
+        (self.rows.len() as u32).saturating_sub(self.get_total_height())
+    }
+
+    fn scroll_to(&mut self, scroll: u32) -> bool {
+        if scroll != self.scroll_idx {
+            self.scroll_idx = scroll;
+            self.base.dirty = true;
+            true
+        } else {
+            false
+        }
+    }
+
     fn update_rows(&mut self) {
-        let width = self.width;
+        self.gutter_width = if self.line_numbers { 2 } else { 0 };
+        loop {
+            let scrollbar_width = if self.scrollbar { 1 } else { 0 };
+            self.rows = self.wrap_text(
+                self.width
+                    .saturating_sub(self.gutter_width + scrollbar_width),
+            );
+            if !self.line_numbers {
+                break;
+            }
+            // Narrowing the text can only add rows, so the gutter only grows until it fits the numbers
+            let gutter_width = self.rows.len().to_string().len() as u32 + 1;
+            if gutter_width <= self.gutter_width {
+                break;
+            }
+            self.gutter_width = gutter_width;
+        }
+        if let Some((ref query, style)) = self.highlight {
+            for row in &mut self.rows {
+                Dialog::highlight_row(row, query, style);
+            }
+        }
+    }
+
+    /// Applies the highlight to the matches in the given row
+    fn highlight_row(row: &mut [ProcessedChar], query: &[char], style: TextStyle) {
+        let highlight_style = OptTextStyle {
+            fg_color: Some(style.fg_color),
+            bg_color: Some(style.bg_color),
+            shakiness: Some(style.shakiness),
+        };
+        let mut idx = 0;
+        while idx + query.len() <= row.len() {
+            let matches = row[idx..idx + query.len()]
+                .iter()
+                .zip(query)
+                .all(|(c, q)| c.character == *q);
+            if matches {
+                for c in &mut row[idx..idx + query.len()] {
+                    c.style = highlight_style.clone();
+                }
+                idx += query.len();
+            } else {
+                idx += 1;
+            }
+        }
+    }
 
+    fn wrap_text(&self, width: u32) -> Vec<Vec<ProcessedChar>> {
         let mut curr_word = Vec::new();
 
         let mut last_style = OptTextStyle {
@@ -194,7 +470,7 @@ impl Dialog {
             bg_color: None,
             shakiness: None,
         };
-        self.rows = Vec::new();
+        let mut rows = Vec::new();
         let mut curr_row = Vec::new();
 
         // Add an empty character at the end, so that every word is added processed properly.
@@ -211,7 +487,7 @@ impl Dialog {
                 || curr_word.len() as u32 >= width
             {
                 // A word breaker found, this is a separate word now.
-                if ((curr_row.len() + curr_word.len() + 1) as u32) <= self.width {
+                if ((curr_row.len() + curr_word.len() + 1) as u32) <= width {
                     if !curr_row.is_empty() {
                         curr_row.push(ProcessedChar {
                             character: ' ',
@@ -220,7 +496,7 @@ impl Dialog {
                     }
                     curr_row.append(&mut curr_word.clone());
                 } else {
-                    self.rows.push(curr_row);
+                    rows.push(curr_row);
                     curr_row = curr_word.clone();
                 }
                 if let Some(last) = curr_word.last() {
@@ -228,7 +504,7 @@ impl Dialog {
                 }
                 // Found \n => make new row
                 if c.character == '\n' {
-                    self.rows.push(curr_row.clone());
+                    rows.push(curr_row.clone());
                     curr_row.clear();
                 }
                 curr_word = Vec::new();
@@ -236,7 +512,8 @@ impl Dialog {
                 curr_word.push(c);
             }
         }
-        self.rows.push(curr_row);
+        rows.push(curr_row);
+        rows
     }
 }
 
@@ -276,27 +553,62 @@ impl InterfaceItem for Dialog {
             bg_color: None,
             shakiness: None,
         };
+        let scrollbar_width = if self.scrollbar && self.width > 0 {
+            1
+        } else {
+            0
+        };
+        let text_width = self
+            .width
+            .saturating_sub(self.gutter_width + scrollbar_width) as usize;
+        let (thumb_pos, thumb_length) = if scrollbar_width > 0 && self.get_total_height() > 0 {
+            self.scrollbar_thumb()
+        } else {
+            (0, 0)
+        };
         for idx in 0..self.get_total_height() {
-            let text: Vec<ProcessedChar>;
-            if let Some(row) = self.rows.get((self.scroll_idx + idx) as usize) {
-                text = row
-                    .iter()
-                    .cloned()
-                    .chain(
+            let row_idx = (self.scroll_idx + idx) as usize;
+            let gutter = match self.rows.get(row_idx) {
+                Some(_) if self.gutter_width > 0 => format!(
+                    "{:>width$} ",
+                    row_idx + 1,
+                    width = self.gutter_width as usize - 1
+                ),
+                _ => " ".repeat(self.gutter_width as usize),
+            };
+            let mut text: Vec<ProcessedChar> = gutter
+                .chars()
+                .map(|character| ProcessedChar {
+                    character,
+                    style: none_style.clone(),
+                })
+                .collect();
+            if let Some(row) = self.rows.get(row_idx) {
+                text.extend(
+                    row.iter().cloned().chain(
                         repeat(ProcessedChar {
                             character: ' ',
                             style: none_style.clone(),
                         })
-                        .take(self.width as usize - row.len()),
-                    )
-                    .collect();
+                        .take(text_width.saturating_sub(row.len())),
+                    ),
+                );
             } else {
-                text = repeat(ProcessedChar {
-                    character: ' ',
+                text.extend(
+                    repeat(ProcessedChar {
+                        character: ' ',
+                        style: none_style.clone(),
+                    })
+                    .take(text_width),
+                );
+            }
+            if scrollbar_width > 0 {
+                let (track, thumb) = self.scrollbar_chars;
+                let on_thumb = idx >= thumb_pos && idx < thumb_pos + thumb_length;
+                text.push(ProcessedChar {
+                    character: if on_thumb { thumb } else { track },
                     style: none_style.clone(),
-                })
-                .take(self.width as usize)
-                .collect();
+                });
             }
             text_buffer.cursor.move_to(self.base.x, self.base.y + idx);
             text_buffer.write_processed(&text);
@@ -310,6 +622,14 @@ impl InterfaceItem for Dialog {
                 handled = handled || self.scroll_down();
             } else if self.up_buttons.contains(&button) {
                 handled = handled || self.scroll_up();
+            } else if self.page_down_buttons.contains(&button) {
+                handled = handled || self.page_down();
+            } else if self.page_up_buttons.contains(&button) {
+                handled = handled || self.page_up();
+            } else if self.end_buttons.contains(&button) {
+                handled = handled || self.scroll_to_bottom();
+            } else if self.home_buttons.contains(&button) {
+                handled = handled || self.scroll_to_top();
             }
         }
         handled
@@ -320,6 +640,17 @@ impl InterfaceItem for Dialog {
             self.processed_text = processor.process(vec![self.text.clone().into()]);
             self.update_rows();
             self.needs_processing = false;
+            self.needs_reflow = false;
+        } else if self.needs_reflow {
+            self.reflow();
+        }
+    }
+
+    fn apply_theme(&mut self, theme: &Theme) {
+        if self.unfocused_style != theme.text || self.focused_style != theme.focused {
+            self.unfocused_style = theme.text;
+            self.focused_style = theme.focused;
+            self.base.dirty = true;
         }
     }
 }
@@ -0,0 +1,130 @@
+use super::{InterfaceItem, InterfaceItemBase, Theme};
+use crate::text_processing::TextProcessor;
+use crate::{keycode_name, Events, TextBuffer, TextStyle, VirtualKeyCode};
+
+#[derive(Debug, Clone)]
+/// Represents a hint of a key binding, such as `[Enter] Confirm`, that can be used in help bars.
+///
+/// The name of the key (see [`keycode_name`](../fn.keycode_name.html)) is drawn in brackets with `key_style`, followed by the description with `label_style`.
+/// The KeyHint can not be focused and does not handle any events.
+///
+/// Example:
+/// ```
+/// use glerminal::menu_systems::KeyHint;
+/// use glerminal::VirtualKeyCode;
+///
+/// KeyHint::new(VirtualKeyCode::Return, "Confirm");
+/// ```
+pub struct KeyHint {
+    /// Style of the bracketed key
+    pub key_style: TextStyle,
+    /// Style of the description
+    pub label_style: TextStyle,
+
+    base: InterfaceItemBase,
+    key: VirtualKeyCode,
+    description: String,
+}
+
+impl KeyHint {
+    /// Initializes a KeyHint with the given key and description
+    pub fn new<T: Into<String>>(key: VirtualKeyCode, description: T) -> KeyHint {
+        KeyHint {
+            key_style: TextStyle {
+                fg_color: [0.2, 0.2, 0.2, 1.0],
+                bg_color: [0.8, 0.8, 0.8, 1.0],
+                ..Default::default()
+            },
+            label_style: TextStyle {
+                fg_color: [0.8, 0.8, 0.8, 1.0],
+                ..Default::default()
+            },
+
+            base: InterfaceItemBase::new(false),
+            key,
+            description: description.into(),
+        }
+    }
+
+    with_base!(KeyHint);
+
+    /// Sets the style of the bracketed key
+    pub fn with_key_style(mut self, style: TextStyle) -> KeyHint {
+        self.key_style = style;
+        self
+    }
+
+    /// Sets the style of the description
+    pub fn with_label_style(mut self, style: TextStyle) -> KeyHint {
+        self.label_style = style;
+        self
+    }
+
+    /// Sets the key of the KeyHint
+    pub fn set_key(&mut self, key: VirtualKeyCode) {
+        self.key = key;
+        self.base.dirty = true;
+    }
+
+    /// Sets the description of the KeyHint
+    pub fn set_description<T: Into<String>>(&mut self, description: T) {
+        self.description = description.into();
+        self.base.dirty = true;
+    }
+
+    /// Returns the key of the KeyHint
+    pub fn get_key(&self) -> VirtualKeyCode {
+        self.key
+    }
+
+    /// Returns the description of the KeyHint
+    pub fn get_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn key_text(&self) -> String {
+        format!("[{}]", keycode_name(self.key))
+    }
+}
+
+impl InterfaceItem for KeyHint {
+    fn get_base(&self) -> &InterfaceItemBase {
+        &self.base
+    }
+
+    fn get_mut_base(&mut self) -> &mut InterfaceItemBase {
+        &mut self.base
+    }
+
+    fn get_total_width(&self) -> u32 {
+        (self.key_text().chars().count() + 1 + self.description.chars().count()) as u32
+    }
+
+    fn get_total_height(&self) -> u32 {
+        1
+    }
+
+    fn draw(&mut self, text_buffer: &mut TextBuffer) {
+        self.base.dirty = false;
+
+        text_buffer.cursor.move_to(self.base.x, self.base.y);
+        text_buffer.cursor.style = self.key_style;
+        text_buffer.write(self.key_text());
+        text_buffer.cursor.style = self.label_style;
+        text_buffer.write(format!(" {}", self.description));
+    }
+
+    fn handle_events(&mut self, _: &Events) -> bool {
+        false
+    }
+
+    fn update(&mut self, _: f32, _: &dyn TextProcessor) {}
+
+    fn apply_theme(&mut self, theme: &Theme) {
+        if self.key_style != theme.accent || self.label_style != theme.text {
+            self.key_style = theme.accent;
+            self.label_style = theme.text;
+            self.base.dirty = true;
+        }
+    }
+}
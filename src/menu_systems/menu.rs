@@ -1,8 +1,8 @@
-use super::InterfaceItem;
+use super::{InterfaceItem, Theme, Window};
 use crate::events::Events;
 use crate::text_buffer::TextBuffer;
 use crate::text_processing::{DefaultProcessor, TextProcessor};
-use glutin::VirtualKeyCode;
+use glutin::{MouseButton, VirtualKeyCode};
 
 /// Represents a list of InterfaceItems that is passed to the Menu when updating
 ///
@@ -51,6 +51,22 @@ impl<'a> MenuList<'a> {
         }
     }
 
+    /// Removes all InterfaceItems from the MenuList, so that it can be filled again.
+    pub fn clear(&mut self) {
+        self.items_ref.clear();
+        self.positions.clear();
+    }
+
+    /// Returns the amount of InterfaceItems in the MenuList
+    pub fn len(&self) -> usize {
+        self.items_ref.len()
+    }
+
+    /// Returns whether the MenuList has no InterfaceItems
+    pub fn is_empty(&self) -> bool {
+        self.items_ref.is_empty()
+    }
+
     pub(crate) fn get_cloned_list(&self) -> Vec<Box<dyn InterfaceItem>> {
         let mut list = Vec::new();
         for item in &self.items_ref {
@@ -71,6 +87,13 @@ pub enum GrowthDirection {
     Right,
     /// Expand it leftwards
     Left,
+    /// Arrange the items rightwards into rows of `cols` items, wrapping downwards.
+    ///
+    /// Left and Right select the previous and next items, Up and Down move between rows.
+    Grid {
+        /// The amount of columns in each row
+        cols: u32,
+    },
 }
 
 /// Represents the position of a menu item in the menu
@@ -101,6 +124,9 @@ pub enum FocusSelection {
 ///
 /// To add a [`TextProcessor`](../text_processing/struct.TextProcessor.html) to the menu, such as the Parser, use [`with_text_processor`](#method.with_text_processor)
 ///
+/// If the menu has more items than fit on the screen, the amount of items drawn can be limited with [`with_max_visible`](#method.with_max_visible),
+/// in which case the menu will scroll to keep the selected item visible.
+///
 /// Example menu usage:
 /// ```no_run
 /// use glerminal::menu_systems::{Filter, Menu, MenuList, MenuPosition, TextInput, TextItem};
@@ -151,15 +177,27 @@ pub struct Menu {
     y: u32,
     focused: bool,
     is_dirty: bool,
+    consumed_input: bool,
     select_idx: u32,
     total_width: u32,
     total_height: u32,
+    // Offset of the top left corner of the drawn items from the position of the menu
+    bounds_offset: (i32, i32),
     cloned_interface_items: Vec<Box<dyn InterfaceItem>>,
 
+    item_spacing: u32,
+    max_visible: Option<u32>,
+    scroll_idx: u32,
+    scroll_indicators: (char, char),
+    // Interior (x, y, width, height) of the window the menu is placed in
+    window: Option<(u32, u32, u32, u32)>,
+
     growth_direction: GrowthDirection,
     focus_selection: FocusSelection,
+    focused_claims_directions: bool,
 
     text_processor: Box<dyn TextProcessor>,
+    theme: Option<Theme>,
 }
 
 impl Default for Menu {
@@ -169,15 +207,25 @@ impl Default for Menu {
             y: 0,
             focused: false,
             is_dirty: true,
+            consumed_input: false,
             select_idx: 0,
             total_width: 0,
             total_height: 0,
+            bounds_offset: (0, 0),
             cloned_interface_items: Vec::new(),
 
+            item_spacing: 0,
+            max_visible: None,
+            scroll_idx: 0,
+            scroll_indicators: ('▲', '▼'),
+            window: None,
+
             growth_direction: GrowthDirection::Down,
             focus_selection: FocusSelection::Keyboard(None, None),
+            focused_claims_directions: false,
 
             text_processor: Box::new(DefaultProcessor),
+            theme: None,
         }
     }
 }
@@ -214,17 +262,73 @@ impl Menu {
         self
     }
 
+    /// Sets whether the focused item claims the arrow keys, so that they are never used for selecting items in the menu. Default is false.
+    ///
+    /// Useful when the focused item uses the arrow keys for it's own navigation, like a `TextInput`, but does not consume every arrow key press.
+    /// Previous and next buttons set with [`FocusSelection`](enum.FocusSelection.html) that are not arrow keys still work.
+    pub fn with_focused_claims_directions(mut self, claims: bool) -> Menu {
+        self.focused_claims_directions = claims;
+        self
+    }
+
     /// Set the text processor for this menu, or in other words, the `TextProcessor` that is given to each `InterfaceItem` in their `update`.
     pub fn with_text_processor<T: 'static + TextProcessor>(mut self, processor: T) -> Menu {
         self.text_processor = Box::new(processor);
         self
     }
 
+    /// Sets the initial theme of the menu, that is applied to all items that support themes (see [`Theme`](struct.Theme.html)).
+    pub fn with_theme(mut self, theme: Theme) -> Menu {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Places the menu inside the given window (see [`set_window`](#method.set_window)).
+    pub fn with_window(mut self, window: &Window) -> Menu {
+        self.set_window(window);
+        self
+    }
+
+    /// Set the amount of empty space between consecutive items in the growth direction. Default is 0.
+    pub fn with_item_spacing(mut self, item_spacing: u32) -> Menu {
+        self.item_spacing = item_spacing;
+        self
+    }
+
+    /// Limits how many items are drawn at once. If there are more items, the menu scrolls so that the selected item stays visible.
+    /// For [`GrowthDirection::Grid`](enum.GrowthDirection.html) this is the amount of visible rows.
+    ///
+    /// None (default) draws every item.
+    pub fn with_max_visible<T: Into<Option<u32>>>(mut self, max_visible: T) -> Menu {
+        self.max_visible = max_visible.into();
+        self
+    }
+
+    /// Sets the characters that are drawn before and after the visible items when the menu has scrolled, default is `('▲', '▼')`.
+    ///
+    /// **Note:** The characters must exist in the font being used.
+    pub fn with_scroll_indicators(mut self, before: char, after: char) -> Menu {
+        self.scroll_indicators = (before, after);
+        self
+    }
+
     /// Sets the position of the menu
     pub fn set_pos(&mut self, pos: (u32, u32)) {
         let (x, y) = pos;
         self.x = x;
         self.y = y;
+        self.is_dirty = true;
+    }
+
+    /// Sets the position of the menu so that it is centered in an area of the given dimensions (width, height), starting from (0, 0).
+    ///
+    /// **Note:** The size of the menu is only known after an `update`, so this uses the size from the latest `update`,
+    /// and the items are moved in the next `update`. If the size of the menu changes, this should be called again.
+    pub fn center_in(&mut self, dimensions: (u32, u32)) {
+        let (width, height) = dimensions;
+        let x = (width.saturating_sub(self.total_width) / 2) as i32 - self.bounds_offset.0;
+        let y = (height.saturating_sub(self.total_height) / 2) as i32 - self.bounds_offset.1;
+        self.set_pos((x.max(0) as u32, y.max(0) as u32));
     }
 
     /// Set whether the menu is focused
@@ -242,22 +346,81 @@ impl Menu {
         self.focus_selection = focus_selection;
     }
 
+    /// Sets whether the focused item claims the arrow keys (see [`with_focused_claims_directions`](#method.with_focused_claims_directions))
+    pub fn set_focused_claims_directions(&mut self, claims: bool) {
+        self.focused_claims_directions = claims;
+    }
+
     /// Set the text processor for this menu, or in other words, the `TextProcessor` that is given to each `InterfaceItem` in their `update`.
     pub fn set_text_processor<T: 'static + TextProcessor>(&mut self, processor: T) {
         self.text_processor = Box::new(processor);
     }
 
+    /// Sets the theme of the menu, that is applied to all items that support themes (see [`Theme`](struct.Theme.html)).
+    ///
+    /// None means no theme is applied and the items keep their own styles.
+    pub fn set_theme<T: Into<Option<Theme>>>(&mut self, theme: T) {
+        self.theme = theme.into();
+    }
+
+    /// Places the menu inside the given window, so that the position of the menu is relative to the top left corner
+    /// of the window's interior (see [`Window::inner_pos`](struct.Window.html#method.inner_pos)).
+    ///
+    /// The positions of the items (see [`get_cloned_list()`](#method.get_cloned_list)) are moved by the offset, so drawing and
+    /// mouse selection both happen inside the window. Items and scroll indicators that do not fit completely inside
+    /// the window's interior are skipped: they are not drawn and can not be hovered or clicked.
+    pub fn set_window(&mut self, window: &Window) {
+        let (x, y) = window.inner_pos();
+        let old_offset = self.window_offset();
+        self.window = Some((x, y, window.width, window.height));
+        self.move_items(old_offset, (x, y));
+    }
+
+    /// Removes the window set with [`set_window`](#method.set_window), so that the menu is no longer clipped or offset.
+    pub fn clear_window(&mut self) {
+        let old_offset = self.window_offset();
+        self.window = None;
+        self.move_items(old_offset, (0, 0));
+    }
+
+    /// Returns the current theme of the menu, if any.
+    pub fn get_theme(&self) -> Option<Theme> {
+        self.theme
+    }
+
+    /// Set the amount of empty space between consecutive items in the growth direction. Default is 0.
+    pub fn set_item_spacing(&mut self, item_spacing: u32) {
+        self.item_spacing = item_spacing;
+        self.is_dirty = true;
+    }
+
+    /// Limits how many items are drawn at once. If there are more items, the menu scrolls so that the selected item stays visible.
+    /// For [`GrowthDirection::Grid`](enum.GrowthDirection.html) this is the amount of visible rows.
+    ///
+    /// None (default) draws every item.
+    pub fn set_max_visible<T: Into<Option<u32>>>(&mut self, max_visible: T) {
+        self.max_visible = max_visible.into();
+        self.is_dirty = true;
+    }
+
+    /// Sets the characters that are drawn before and after the visible items when the menu has scrolled, default is `('▲', '▼')`.
+    ///
+    /// **Note:** The characters must exist in the font being used.
+    pub fn set_scroll_indicators(&mut self, before: char, after: char) {
+        self.scroll_indicators = (before, after);
+    }
+
     /// Get the position of the Menu
     pub fn get_pos(&self) -> (u32, u32) {
         (self.x, self.y)
     }
 
-    /// Gets the width this Menu should take up when drawn
+    /// Gets the width this Menu should take up when drawn, calculated from the visible items in `update`
     pub fn get_total_width(&self) -> u32 {
         self.total_width
     }
 
-    /// Get the height this Menu should take up when drawn
+    /// Get the height this Menu should take up when drawn, calculated from the visible items in `update`
     pub fn get_total_height(&self) -> u32 {
         self.total_height
     }
@@ -273,6 +436,11 @@ impl Menu {
         self.select_idx
     }
 
+    /// Return the index of the first visible item. Always 0 if max visible is not set.
+    pub fn get_scroll_idx(&self) -> u32 {
+        self.scroll_idx
+    }
+
     /// Tries to set the select idx for the Menu. If idx is greater than get_item_count() - 1, it will cap to that.
     ///
     /// **Note:** Uses a cloned version of the list that is cloned in `update`. (See [`get_cloned_list()`](#method.get_cloned_list))  
@@ -299,6 +467,7 @@ impl Menu {
                 GrowthDirection::Down => VirtualKeyCode::Up,
                 GrowthDirection::Left => VirtualKeyCode::Right,
                 GrowthDirection::Right => VirtualKeyCode::Left,
+                GrowthDirection::Grid { .. } => VirtualKeyCode::Left,
             }
         }
     }
@@ -319,10 +488,33 @@ impl Menu {
                 GrowthDirection::Down => VirtualKeyCode::Down,
                 GrowthDirection::Left => VirtualKeyCode::Left,
                 GrowthDirection::Right => VirtualKeyCode::Right,
+                GrowthDirection::Grid { .. } => VirtualKeyCode::Right,
             }
         }
     }
 
+    /// Returns the index of the item that is currently focused, if any. (See [`get_cloned_list()`](#method.get_cloned_list))
+    pub fn get_focused_item_index(&self) -> Option<u32> {
+        self.cloned_interface_items
+            .iter()
+            .position(|item| item.get_base().is_focused())
+            .map(|idx| idx as u32)
+    }
+
+    /// Returns the item that is currently focused, if any. (See [`get_cloned_list()`](#method.get_cloned_list))
+    pub fn focused_item(&self) -> Option<&dyn InterfaceItem> {
+        self.get_focused_item_index()
+            .map(|idx| &*self.cloned_interface_items[idx as usize])
+    }
+
+    /// Returns whether the menu or its focused item consumed any input during the last `update`,
+    /// ie. the selection was moved or the focused item handled the events.
+    ///
+    /// Useful for preventing the rest of the application from acting on the same input.
+    pub fn consumed_input(&self) -> bool {
+        self.consumed_input
+    }
+
     /// Get the currently cloned items in the menu.
     ///
     /// In every `update`, if the items given are dirty (or the amount of items has changed),
@@ -347,16 +539,14 @@ impl Menu {
         self.select_idx = (self.select_idx as i32).min(length - 1).max(0) as u32;
 
         // Handle events if focused
-        if self.focused {
-            self.handle_events(events, list, text_buffer);
-        }
+        self.consumed_input = self.focused && self.handle_events(events, list, text_buffer);
 
         // Ensure that any unselectable menu items aren't selected. If none are found, c'est la vie
         let start_idx = (self.select_idx as i32).min(length - 1).max(0) as u32;
-        while {
+        while length > 0 && {
             !list.items_ref[self.select_idx as usize]
                 .get_base()
-                .can_be_focused
+                .is_selectable()
         } {
             self.select_idx = (((self.select_idx as i32) + 1) % length) as u32;
             if self.select_idx == start_idx {
@@ -364,45 +554,123 @@ impl Menu {
             }
         }
 
+        // Scroll so that the selected item is visible
+        let scrolled = self.update_scroll(length as u32);
+
+        let hovered_idx = self.item_at(events, text_buffer, false);
+
         // Update children and focus the focused child.
         for (idx, item) in (&mut list.items_ref).iter_mut().enumerate() {
             item.get_mut_base()
                 .set_focused((self.select_idx == idx as u32) && self.focused);
+            item.get_mut_base().set_hovered(hovered_idx == Some(idx));
+            if let Some(ref theme) = self.theme {
+                item.apply_theme(theme);
+            }
             item.update(delta, &*self.text_processor);
         }
 
         // Check if the children are dirty, if they are then update them to be drawn
-        self.is_dirty = self.children_are_dirty(&mut list.items_ref);
-        if self.is_dirty {
+        let children_are_dirty = self.children_are_dirty(&mut list.items_ref);
+        let is_dirty = self.is_dirty || children_are_dirty || scrolled;
+        if is_dirty {
             self.set_new_positions(&list);
         }
+        self.is_dirty = false;
 
-        self.is_dirty
+        is_dirty
     }
 
     /// Draw the menu and any saved children (see [`update(input, children)`](#method.update))
+    ///
+    /// If the menu is placed in a window (see [`set_window`](#method.set_window)), items that do not fit inside it are skipped.
     pub fn draw(&mut self, text_buffer: &mut TextBuffer) {
+        let (start, end) = self.visible_range();
+        for idx in start..end {
+            if self.is_drawn(&*self.cloned_interface_items[idx]) {
+                self.cloned_interface_items[idx].draw(text_buffer);
+            }
+        }
+        self.draw_scroll_indicators(text_buffer);
+    }
+
+    /// Places the menu inside the given window (see [`set_window`](#method.set_window)) and draws it.
+    pub fn draw_in_window(&mut self, window: &Window, text_buffer: &mut TextBuffer) {
+        self.set_window(window);
+        self.draw(text_buffer);
+    }
+
+    /// Returns whether the item is visible and fits completely inside the window, if one is set.
+    fn is_drawn(&self, item: &dyn InterfaceItem) -> bool {
+        if !item.get_base().is_visible() {
+            return false;
+        }
+        match self.window {
+            Some((clip_x, clip_y, clip_width, clip_height)) => {
+                let (x, y) = item.get_base().get_pos();
+                x >= clip_x
+                    && y >= clip_y
+                    && x.saturating_add(item.get_total_width()) <= clip_x + clip_width
+                    && y.saturating_add(item.get_total_height()) <= clip_y + clip_height
+            }
+            None => true,
+        }
+    }
+
+    fn window_offset(&self) -> (u32, u32) {
+        match self.window {
+            Some((x, y, _, _)) => (x, y),
+            None => (0, 0),
+        }
+    }
+
+    /// Moves the positions of the cloned items from being relative to `from` to being relative to `to`.
+    fn move_items(&mut self, from: (u32, u32), to: (u32, u32)) {
+        if from == to {
+            return;
+        }
         for item in &mut self.cloned_interface_items {
-            item.draw(text_buffer);
+            let (x, y) = item.get_base().get_pos();
+            item.get_mut_base().set_pos((
+                x.saturating_sub(from.0) + to.0,
+                y.saturating_sub(from.1) + to.1,
+            ));
         }
     }
 
-    fn handle_events(&mut self, events: &Events, list: &mut MenuList, text_buffer: &TextBuffer) {
+    /// Handles the events for the menu and the focused child, returning whether any input was consumed.
+    fn handle_events(
+        &mut self,
+        events: &Events,
+        list: &mut MenuList,
+        text_buffer: &TextBuffer,
+    ) -> bool {
         let length = list.items_ref.len();
 
         // There isn't anything to handle.
         if length == 0 {
             self.select_idx = 0;
-            return;
+            return false;
         }
 
         // Handle input for focused child and consume input if necessary.
         let mut focused_handled_input = false;
+        let mut directions_claimed = false;
         if let Some(item) = (&mut list.items_ref).get_mut(self.select_idx as usize) {
             focused_handled_input = item.handle_events(events);
+            directions_claimed = self.focused_claims_directions && item.get_base().can_be_focused;
         }
+        // Whether the button can be used for selection, or if it is claimed by the focused item
+        let usable = |button: VirtualKeyCode| match button {
+            VirtualKeyCode::Up
+            | VirtualKeyCode::Down
+            | VirtualKeyCode::Left
+            | VirtualKeyCode::Right => !directions_claimed,
+            _ => true,
+        };
 
         // Handle input for the menu (selecting), if focused child didn't consume the last inpout
+        let mut consumed = focused_handled_input;
         if !focused_handled_input {
             let keyboard_focus = match self.focus_selection {
                 FocusSelection::Keyboard(..) => true,
@@ -411,7 +679,9 @@ impl Menu {
             };
             if keyboard_focus {
                 // Do selection with the keyboard
-                if events.keyboard.was_just_pressed(self.get_previous_button()) {
+                let previous_button = self.get_previous_button();
+                if usable(previous_button) && events.keyboard.was_just_pressed(previous_button) {
+                    consumed = true;
                     self.select_idx =
                         (((self.select_idx as i32 + length as i32) - 1) % length as i32) as u32;
 
@@ -419,7 +689,7 @@ impl Menu {
                     while {
                         !list.items_ref[self.select_idx as usize]
                             .get_base()
-                            .can_be_focused
+                            .is_selectable()
                     } {
                         self.select_idx =
                             (((self.select_idx as i32 + length as i32) - 1) % length as i32) as u32;
@@ -428,9 +698,30 @@ impl Menu {
                         }
                     }
                 }
-                if events.keyboard.was_just_pressed(self.get_next_button()) {
+                let next_button = self.get_next_button();
+                if usable(next_button) && events.keyboard.was_just_pressed(next_button) {
+                    consumed = true;
                     self.select_idx = (((self.select_idx as i32) + 1) % length as i32) as u32;
                 }
+
+                // Move between rows in a grid
+                if let (GrowthDirection::Grid { cols }, false) =
+                    (self.growth_direction, directions_claimed)
+                {
+                    let cols = cols.max(1);
+                    if events.keyboard.was_just_pressed(VirtualKeyCode::Up)
+                        && self.select_idx >= cols
+                    {
+                        consumed = true;
+                        self.select_idx -= cols;
+                    }
+                    if events.keyboard.was_just_pressed(VirtualKeyCode::Down)
+                        && self.select_idx + cols < length as u32
+                    {
+                        consumed = true;
+                        self.select_idx += cols;
+                    }
+                }
             }
 
             // Do any selection with mouse
@@ -441,52 +732,178 @@ impl Menu {
             };
 
             if mouse_focus {
-                let grow_right = match self.growth_direction {
-                    GrowthDirection::Left => false,
-                    _ => true,
-                };
-                let grow_down = match self.growth_direction {
-                    GrowthDirection::Up => false,
-                    _ => true,
-                };
-                if let Some(loc) = events.cursor.get_location(&text_buffer) {
-                    for idx in 0..self.cloned_interface_items.len() {
-                        let item = &self.cloned_interface_items[idx];
-                        let base = item.get_base();
-                        let idx = idx as u32;
-
-                        if !base.can_be_focused {
-                            continue;
-                        }
-                        let (x, y) = (base.get_pos().0, base.get_pos().1);
-                        let width = item.get_total_width();
-                        let height = item.get_total_height();
-
-                        let (x0, x1);
-                        if grow_right {
-                            x0 = x;
-                            x1 = x + width - 1;
-                        } else {
-                            x0 = x - width + 1;
-                            x1 = x;
-                        }
+                if let Some(idx) = self.item_at(events, text_buffer, true) {
+                    consumed = consumed || self.select_idx != idx as u32;
+                    self.select_idx = idx as u32;
+                }
+            }
+        }
 
-                        let (y0, y1);
-                        if grow_down {
-                            y0 = y;
-                            y1 = y + height - 1;
-                        } else {
-                            y0 = y - height + 1;
-                            y1 = y;
-                        }
+        consumed
+    }
 
-                        if loc.0 >= x0 && loc.0 <= x1 && loc.1 >= y0 && loc.1 <= y1 {
-                            self.select_idx = idx;
-                            break;
-                        }
-                    }
-                }
+    /// Returns the index of the item that was right-clicked this frame, if any.
+    ///
+    /// Useful for opening context menus. Uses the positions of the cloned list (see [`get_cloned_list()`](#method.get_cloned_list)).
+    pub fn right_clicked_item(&self, events: &Events, text_buffer: &TextBuffer) -> Option<usize> {
+        if events.mouse.was_just_pressed(MouseButton::Right) {
+            self.item_at(events, text_buffer, false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the visible item under the mouse cursor, if any.
+    /// If `focusable_only` is true, only items that can be focused are considered.
+    fn item_at(
+        &self,
+        events: &Events,
+        text_buffer: &TextBuffer,
+        focusable_only: bool,
+    ) -> Option<usize> {
+        let grow_right = match self.growth_direction {
+            GrowthDirection::Left => false,
+            _ => true,
+        };
+        let grow_down = match self.growth_direction {
+            GrowthDirection::Up => false,
+            _ => true,
+        };
+        let loc = events.cursor.get_location(text_buffer)?;
+        let (start, end) = self.visible_range();
+        for idx in start..end {
+            let item = &self.cloned_interface_items[idx];
+            let base = item.get_base();
+
+            if !self.is_drawn(&**item) || (focusable_only && !base.can_be_focused) {
+                continue;
+            }
+            let (x, y) = (base.get_pos().0, base.get_pos().1);
+            let width = item.get_total_width();
+            let height = item.get_total_height();
+            // Items without any size can not be under the cursor
+            if width == 0 || height == 0 {
+                continue;
+            }
+
+            let (x0, x1);
+            if grow_right {
+                x0 = x;
+                x1 = x + width - 1;
+            } else {
+                x0 = (x + 1).saturating_sub(width);
+                x1 = x;
+            }
+
+            let (y0, y1);
+            if grow_down {
+                y0 = y;
+                y1 = y + height - 1;
+            } else {
+                y0 = (y + 1).saturating_sub(height);
+                y1 = y;
+            }
+
+            if loc.0 >= x0 && loc.0 <= x1 && loc.1 >= y0 && loc.1 <= y1 {
+                return Some(idx);
+            }
+        }
+        None
+    }
+
+    /// Move the scroll so that the selected item is visible, returns whether the scroll changed.
+    fn update_scroll(&mut self, length: u32) -> bool {
+        let previous_scroll = self.scroll_idx;
+        if let Some(max_visible) = self.max_visible {
+            // Grids scroll a whole row at a time
+            let step = match self.growth_direction {
+                GrowthDirection::Grid { cols } => cols.max(1),
+                _ => 1,
+            };
+            let max_visible = max_visible.max(1);
+            let select_row = self.select_idx / step;
+            let mut scroll_row = self.scroll_idx / step;
+            if select_row < scroll_row {
+                scroll_row = select_row;
+            } else if select_row >= scroll_row + max_visible {
+                scroll_row = select_row + 1 - max_visible;
             }
+            let rows = length / step + (length % step).min(1);
+            self.scroll_idx = scroll_row.min(rows.saturating_sub(max_visible)) * step;
+        } else {
+            self.scroll_idx = 0;
+        }
+        previous_scroll != self.scroll_idx
+    }
+
+    /// Returns the range of indices of the cloned items that should be drawn.
+    fn visible_range(&self) -> (usize, usize) {
+        let length = self.cloned_interface_items.len();
+        let start = (self.scroll_idx as usize).min(length);
+        if let Some(max_visible) = self.max_visible {
+            let step = match self.growth_direction {
+                GrowthDirection::Grid { cols } => cols.max(1) as usize,
+                _ => 1,
+            };
+            (
+                start,
+                (start + max_visible.max(1) as usize * step).min(length),
+            )
+        } else {
+            (start, length)
+        }
+    }
+
+    fn draw_scroll_indicators(&self, text_buffer: &mut TextBuffer) {
+        let (start, end) = self.visible_range();
+        if start == end {
+            return;
+        }
+        let (before, after) = self.scroll_indicators;
+        text_buffer.cursor.style = Default::default();
+
+        if start > 0 {
+            let item = &self.cloned_interface_items[start];
+            let (x, y) = item.get_base().get_pos();
+            let pos = match self.growth_direction {
+                GrowthDirection::Down | GrowthDirection::Grid { .. } => (x as i32, y as i32 - 1),
+                GrowthDirection::Up => (x as i32, (y + item.get_total_height()) as i32),
+                GrowthDirection::Right => (x as i32 - 1, y as i32),
+                GrowthDirection::Left => ((x + item.get_total_width()) as i32, y as i32),
+            };
+            Menu::put_indicator(text_buffer, pos, self.window, before);
+        }
+        if end < self.cloned_interface_items.len() {
+            let item = &self.cloned_interface_items[end - 1];
+            let (x, y) = item.get_base().get_pos();
+            let pos = match self.growth_direction {
+                GrowthDirection::Down | GrowthDirection::Grid { .. } => {
+                    (x as i32, (y + item.get_total_height()) as i32)
+                }
+                GrowthDirection::Up => (x as i32, y as i32 - 1),
+                GrowthDirection::Right => ((x + item.get_total_width()) as i32, y as i32),
+                GrowthDirection::Left => (x as i32 - 1, y as i32),
+            };
+            Menu::put_indicator(text_buffer, pos, self.window, after);
+        }
+    }
+
+    fn put_indicator(
+        text_buffer: &mut TextBuffer,
+        pos: (i32, i32),
+        clip: Option<(u32, u32, u32, u32)>,
+        character: char,
+    ) {
+        let (width, height) = text_buffer.get_dimensions();
+        let (x, y) = pos;
+        let (clip_x, clip_y, clip_width, clip_height) = clip.unwrap_or((0, 0, width, height));
+        if x >= clip_x as i32
+            && y >= clip_y as i32
+            && (x as u32) < (clip_x + clip_width).min(width)
+            && (y as u32) < (clip_y + clip_height).min(height)
+        {
+            text_buffer.cursor.move_to(x as u32, y as u32);
+            text_buffer.put_char(character);
         }
     }
 
@@ -508,6 +925,7 @@ impl Menu {
         let mut off: (i32, i32) = (0, 0);
         let mut last_off: (i32, i32) = (0, 0);
         let mut last_pos: (i32, i32) = (self.x as i32, self.y as i32);
+        let spacing = self.item_spacing as i32;
 
         // Set the positions of the children relative to the growth direction and their own positions
         match self.growth_direction {
@@ -526,7 +944,7 @@ impl Menu {
                     item.get_mut_base()
                         .set_pos((last_pos.0 as u32, last_pos.1 as u32));
 
-                    last_off = (0, item.get_total_height() as i32);
+                    last_off = (0, Menu::item_extent(&**item, spacing).1);
                     off.1 += last_off.1;
                 }
             }
@@ -534,7 +952,8 @@ impl Menu {
                 for (idx, item) in (&mut self.cloned_interface_items).iter_mut().enumerate() {
                     let position = &list.positions[idx];
 
-                    last_off = (0, -(item.get_total_height() as i32));
+                    let spacing = if idx > 0 { spacing } else { 0 };
+                    last_off = (0, -Menu::item_extent(&**item, spacing).1);
 
                     last_pos = Menu::calc_new_pos(
                         self.x as i32,
@@ -565,7 +984,7 @@ impl Menu {
                     item.get_mut_base()
                         .set_pos((last_pos.0 as u32, last_pos.1 as u32));
 
-                    last_off = (item.get_total_width() as i32, 0);
+                    last_off = (Menu::item_extent(&**item, spacing).0, 0);
                     off.0 += last_off.0;
                 }
             }
@@ -573,7 +992,8 @@ impl Menu {
                 for (idx, item) in (&mut self.cloned_interface_items).iter_mut().enumerate() {
                     let position = &list.positions[idx];
 
-                    last_off = (-(item.get_total_width() as i32), 0);
+                    let spacing = if idx > 0 { spacing } else { 0 };
+                    last_off = (-Menu::item_extent(&**item, spacing).0, 0);
 
                     last_pos = Menu::calc_new_pos(
                         self.x as i32,
@@ -589,7 +1009,103 @@ impl Menu {
                     off.0 += last_off.0;
                 }
             }
+            GrowthDirection::Grid { cols } => {
+                let cols = cols.max(1) as usize;
+                let rows = self.cloned_interface_items.len() / cols + 1;
+
+                // Every column is as wide as it's widest item, and every row as tall as it's tallest item
+                let mut col_widths = vec![0; cols];
+                let mut row_heights = vec![0; rows];
+                for (idx, item) in self.cloned_interface_items.iter().enumerate() {
+                    let (col, row) = (idx % cols, idx / cols);
+                    let (width, height) = Menu::item_extent(&**item, 0);
+                    col_widths[col] = col_widths[col].max(width);
+                    row_heights[row] = row_heights[row].max(height);
+                }
+
+                for (idx, item) in self.cloned_interface_items.iter_mut().enumerate() {
+                    let (col, row) = (idx % cols, idx / cols);
+                    let cell_x = self.x as i32
+                        + col_widths[..col].iter().sum::<i32>()
+                        + col as i32 * spacing;
+                    let cell_y = self.y as i32
+                        + row_heights[..row].iter().sum::<i32>()
+                        + row as i32 * spacing;
+
+                    let pos = match list.positions[idx] {
+                        MenuPosition::Relative(x, y) | MenuPosition::RelativeToLast(x, y) => {
+                            (cell_x + x, cell_y + y)
+                        }
+                        MenuPosition::Absolute(x, y) => (x as i32, y as i32),
+                    };
+                    item.get_mut_base().set_pos((pos.0 as u32, pos.1 as u32));
+                }
+            }
+        }
+
+        // Move the items so that the first visible item is where the first item would be
+        if self.scroll_idx > 0 && (self.scroll_idx as usize) < self.cloned_interface_items.len() {
+            let first = self.cloned_interface_items[0].get_base().get_pos();
+            let scrolled = self.cloned_interface_items[self.scroll_idx as usize]
+                .get_base()
+                .get_pos();
+            let scroll_off = (
+                scrolled.0 as i32 - first.0 as i32,
+                scrolled.1 as i32 - first.1 as i32,
+            );
+            for (idx, item) in self.cloned_interface_items.iter_mut().enumerate() {
+                if let MenuPosition::Absolute(..) = list.positions[idx] {
+                    continue;
+                }
+                let (x, y) = item.get_base().get_pos();
+                item.get_mut_base().set_pos((
+                    (x as i32 - scroll_off.0).max(0) as u32,
+                    (y as i32 - scroll_off.1).max(0) as u32,
+                ));
+            }
+        }
+
+        self.update_bounds();
+        let offset = self.window_offset();
+        self.move_items((0, 0), offset);
+    }
+
+    /// Returns the space the item takes in the menu (width, height), with the spacing added. Invisible items take no space.
+    fn item_extent(item: &dyn InterfaceItem, spacing: i32) -> (i32, i32) {
+        if item.get_base().is_visible() {
+            (
+                item.get_total_width() as i32 + spacing,
+                item.get_total_height() as i32 + spacing,
+            )
+        } else {
+            (0, 0)
+        }
+    }
+
+    /// Calculate the total width and height, and the bounds offset, from the visible items
+    fn update_bounds(&mut self) {
+        let (start, end) = self.visible_range();
+        let mut bounds: Option<(i32, i32, i32, i32)> = None;
+        for item in &self.cloned_interface_items[start..end] {
+            if !item.get_base().is_visible() {
+                continue;
+            }
+            let (x, y) = item.get_base().get_pos();
+            let (x0, y0) = (x as i32, y as i32);
+            let x1 = x0 + item.get_total_width() as i32;
+            let y1 = y0 + item.get_total_height() as i32;
+            bounds = Some(match bounds {
+                Some((min_x, min_y, max_x, max_y)) => {
+                    (min_x.min(x0), min_y.min(y0), max_x.max(x1), max_y.max(y1))
+                }
+                None => (x0, y0, x1, y1),
+            });
         }
+        let (min_x, min_y, max_x, max_y) =
+            bounds.unwrap_or((self.x as i32, self.y as i32, self.x as i32, self.y as i32));
+        self.total_width = (max_x - min_x) as u32;
+        self.total_height = (max_y - min_y) as u32;
+        self.bounds_offset = (min_x - self.x as i32, min_y - self.y as i32);
     }
 
     /// Gets the correct position of the menu-item relative to the
@@ -28,6 +28,7 @@ pub struct MenuSwitcher {
     select_idx: u32,
     selection_method: MenuSelectionMethod,
     focused: bool,
+    just_switched: bool,
 
     previous_button: VirtualKeyCode,
     next_button: VirtualKeyCode,
@@ -40,6 +41,7 @@ impl Default for MenuSwitcher {
             select_idx: 0,
             selection_method: Default::default(),
             focused: true,
+            just_switched: false,
 
             previous_button: VirtualKeyCode::Left,
             next_button: VirtualKeyCode::Right,
@@ -55,6 +57,7 @@ impl MenuSwitcher {
             select_idx: 0,
             selection_method: Default::default(),
             focused: true,
+            just_switched: false,
 
             previous_button: previous,
             next_button: next,
@@ -93,10 +96,22 @@ impl MenuSwitcher {
         self.select_idx = idx;
     }
 
+    /// Return the index of the Menu that is currently selected.
+    pub fn get_focused_menu_idx(&self) -> u32 {
+        self.select_idx
+    }
+
+    /// Returns whether the selected Menu changed during the last update.
+    pub fn just_switched(&self) -> bool {
+        self.just_switched
+    }
+
     /// Update the menu switcher, change select indexes if necessary and handle events.
     pub fn update(&mut self, events: &Events, list: &mut [&mut Menu]) {
         let length = list.len() as u32;
 
+        self.just_switched = false;
+
         // Nothing to update
         if length == 0 {
             self.select_idx = 0;
@@ -121,6 +136,7 @@ impl MenuSwitcher {
         }
 
         if self.previous_selection_idx != self.select_idx {
+            self.just_switched = true;
             let p_select_idx = list[self.previous_selection_idx as usize].get_select_idx();
 
             let prev_menu = &list[self.previous_selection_idx as usize];
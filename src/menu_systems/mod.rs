@@ -17,6 +17,9 @@
 //! - [TextInput](struct.TextInput.html), can accept text input that can be get with `get_text`.
 //! - [Dialog](struct.Dialog.html), can be used to display large volumes of text compactly.
 //! - [Checkbox](struct.Checkbox.html), can be checked (and unchecked), like a check- or radiobox (if using [CheckboxGroup](struct.CheckboxGroup.html)).
+//! - [KeyHint](struct.KeyHint.html), displays a key binding and its description, ie. `[Enter] Confirm`.
+//!
+//! The styles of the items can be set all at once by giving the Menu a [Theme](struct.Theme.html).
 //!
 //! **Note:** This module requires _menu_systems_ feature to be enabled.
 //!
@@ -102,32 +105,51 @@
 //! }
 //! ```
 
+/// If you're creating a struct that has `unfocused_style: TextStyle` and `focused_style: TextStyle` fields, calling `with_style!(Struct)` within
+/// the impl-call can be useful. This will add two useful functions for setting initial styles
+/// - `with_unfocused_style(mut self, style: TextStyle) -> Struct`
+/// - `with_focused_style(mut self, style: TextStyle) -> Struct`
+///
+/// See [`InterfaceItem`](menu_systems/trait.InterfaceItem.html) for an example.
+#[macro_export]
 macro_rules! with_style {
     ($name:ident) => {
         /// Set the style when this item is unfocused
-        pub fn with_unfocused_style(mut self, style: TextStyle) -> $name {
+        pub fn with_unfocused_style(mut self, style: $crate::TextStyle) -> $name {
             self.unfocused_style = style;
             self
         }
 
         /// Set the initial colors when it is focused
-        pub fn with_focused_style(mut self, style: TextStyle) -> $name {
+        pub fn with_focused_style(mut self, style: $crate::TextStyle) -> $name {
             self.focused_style = style;
             self
         }
     };
 }
 
+/// If you're creating a struct that has `button_press_inputs: Vec<VirtualKeyCode>`, `mouse_button_press_inputs: Vec<MouseButton>`
+/// and `was_just_pressed: bool` fields, calling `with_set_pressable!(Struct)` within the impl-call can be useful.
+/// This will add functions for setting the inputs that press the item, and checking whether it was pressed
+/// - `with_button_press_inputs(mut self, buttons: Vec<VirtualKeyCode>) -> Struct`
+/// - `with_mouse_button_press_inputs(mut self, buttons: Vec<MouseButton>) -> Struct`
+/// - `was_just_pressed(&self) -> bool`
+///
+/// Setting `was_just_pressed` in `handle_events` is up to the struct.
+#[macro_export]
 macro_rules! with_set_pressable {
     ($name:ident) => {
         /// Set the buttons which trigger `was_just_pressed`
-        pub fn with_button_press_inputs(mut self, buttons: Vec<VirtualKeyCode>) -> $name {
+        pub fn with_button_press_inputs(mut self, buttons: Vec<$crate::VirtualKeyCode>) -> $name {
             self.button_press_inputs = buttons;
             self
         }
 
         /// Set the mouse buttons which trigger `was_just_pressed`
-        pub fn with_mouse_button_press_inputs(mut self, buttons: Vec<MouseButton>) -> $name {
+        pub fn with_mouse_button_press_inputs(
+            mut self,
+            buttons: Vec<$crate::MouseButton>,
+        ) -> $name {
             self.mouse_button_press_inputs = buttons;
             self
         }
@@ -157,6 +179,63 @@ macro_rules! with_base {
             self.base.set_focused(focused);
             self
         }
+
+        /// Set whether the item is initially visible or not
+        pub fn with_visible(mut self, visible: bool) -> $name {
+            self.base.set_visible(visible);
+            self
+        }
+    };
+}
+
+/// Combines [`with_base!`](macro.with_base.html), [`with_set_pressable!`](macro.with_set_pressable.html)
+/// and [`with_style!`](macro.with_style.html) into one call, for pressable items that have focused and unfocused styles,
+/// like [`TextItem`](menu_systems/struct.TextItem.html).
+///
+/// The struct needs to have the fields required by all three macros:
+/// `base`, `button_press_inputs`, `mouse_button_press_inputs`, `was_just_pressed`, `unfocused_style` and `focused_style`.
+///
+/// ```
+/// use glerminal::menu_systems::InterfaceItemBase;
+/// use glerminal::{with_interface_item, MouseButton, TextStyle, VirtualKeyCode};
+///
+/// #[derive(Clone)]
+/// struct Button {
+///     base: InterfaceItemBase,
+///     button_press_inputs: Vec<VirtualKeyCode>,
+///     mouse_button_press_inputs: Vec<MouseButton>,
+///     was_just_pressed: bool,
+///     unfocused_style: TextStyle,
+///     focused_style: TextStyle,
+/// }
+///
+/// impl Button {
+///     pub fn new() -> Button {
+///         Button {
+///             base: InterfaceItemBase::new(true),
+///             button_press_inputs: vec![VirtualKeyCode::Return],
+///             mouse_button_press_inputs: vec![MouseButton::Left],
+///             was_just_pressed: false,
+///             unfocused_style: Default::default(),
+///             focused_style: TextStyle::inverted(),
+///         }
+///     }
+///
+///     with_interface_item!(Button);
+/// }
+///
+/// let button = Button::new()
+///     .with_pos((2, 1))
+///     .with_button_press_inputs(vec![VirtualKeyCode::Space])
+///     .with_unfocused_style(TextStyle::warning());
+/// assert!(!button.was_just_pressed());
+/// ```
+#[macro_export]
+macro_rules! with_interface_item {
+    ($name:ident) => {
+        $crate::with_base!($name);
+        $crate::with_set_pressable!($name);
+        $crate::with_style!($name);
     };
 }
 
@@ -166,26 +245,35 @@ macro_rules! with_base {
 //     self
 // }
 
+mod canvas;
 mod checkbox;
 mod dialog;
+mod key_hint;
 mod menu;
 mod menu_switcher;
 mod text_input;
 mod text_item;
+mod theme;
 mod window;
 
+pub use self::canvas::Canvas;
 pub use self::checkbox::{Checkbox, CheckboxGroup};
 pub use self::dialog::Dialog;
+pub use self::key_hint::KeyHint;
 pub use self::menu::{FocusSelection, GrowthDirection, Menu, MenuList, MenuPosition};
 pub use self::menu_switcher::{MenuSelectionMethod, MenuSwitcher};
 pub use self::text_input::TextInput;
 pub use self::text_item::TextItem;
-pub use self::window::{BorderChars, Window};
+pub use self::theme::Theme;
+pub use self::window::Window;
+pub use crate::text_buffer::BorderChars;
 
 use crate::events::Events;
 use crate::text_buffer::TextBuffer;
 use crate::text_processing::TextProcessor;
 
+use std::any::Any;
+
 /// Represents a single menu item: an item that is somewhere, can handle events and can be drawn.
 ///
 /// Current pre-implemented items to use in Menus are
@@ -200,13 +288,15 @@ use crate::text_processing::TextProcessor;
 /// A simple example of how to make an InterfaceItem that you can use for Menus
 /// ```
 /// use glerminal::menu_systems::{InterfaceItem, InterfaceItemBase};
-/// use glerminal::{with_base, Events, TextBuffer, TextStyle};
+/// use glerminal::{with_base, with_style, Events, TextBuffer, TextStyle};
 /// use glerminal::text_processing::TextProcessor;
 ///
 /// #[derive(Clone)]
 /// struct TextLabel {
 ///     base: InterfaceItemBase,
 ///     text: String,
+///     unfocused_style: TextStyle,
+///     focused_style: TextStyle,
 /// }
 ///
 /// impl TextLabel {
@@ -214,12 +304,22 @@ use crate::text_processing::TextProcessor;
 ///         TextLabel {
 ///             base: InterfaceItemBase::new(false),
 ///             text: text,
+///             unfocused_style: TextStyle {
+///                 fg_color: [0.8, 0.8, 0.8, 1.0],
+///                 ..Default::default()
+///             },
+///             focused_style: TextStyle::inverted(),
 ///         }
 ///     }
 ///
 ///     with_base!(TextLabel);
+///     with_style!(TextLabel);
 /// }
 ///
+/// let label = TextLabel::new("Hello".to_owned())
+///     .with_pos((2, 1))
+///     .with_unfocused_style(TextStyle::warning());
+///
 /// impl InterfaceItem for TextLabel {
 ///     fn get_base(&self) -> &InterfaceItemBase {
 ///         &self.base
@@ -241,10 +341,10 @@ use crate::text_processing::TextProcessor;
 ///         self.base.dirty = false;
 ///         let pos = self.base.get_pos();
 ///
-///         text_buffer.cursor.style = TextStyle {
-///             fg_color: [0.8, 0.8, 0.8, 1.0],
-///             bg_color: [0.0, 0.0, 0.0, 0.0],
-///             ..Default::default()
+///         text_buffer.cursor.style = if self.base.is_focused() {
+///             self.focused_style
+///         } else {
+///             self.unfocused_style
 ///         };
 ///         text_buffer.cursor.move_to(pos.0, pos.1);
 ///         text_buffer.write(self.text.clone());
@@ -281,6 +381,11 @@ pub trait InterfaceItem: InterfaceItemClone {
     /// Update this InterfaceItem; delta is given in seconds. (see [Terminal.delta_time()](../terminal/struct.Terminal.html)).
     /// Also process any text that has changed since last update.
     fn update(&mut self, delta: f32, processor: &TextProcessor);
+    /// Apply the styles of the given theme to this InterfaceItem, marking it dirty if the styles changed.
+    ///
+    /// Called by [`Menu`](struct.Menu.html) before every `update` if it has a theme (see [`Menu::with_theme`](struct.Menu.html#method.with_theme)).
+    /// By default does nothing, meaning the item does not use themes.
+    fn apply_theme(&mut self, _theme: &Theme) {}
 }
 
 /// Represents a cloneable InterfaceItem; You should never implement this yourself, but instead
@@ -296,12 +401,25 @@ pub trait InterfaceItem: InterfaceItemClone {
 pub trait InterfaceItemClone {
     /// Make a box of the cloned InterfaceItem
     fn clone_box(&self) -> Box<dyn InterfaceItem>;
+    /// Get the InterfaceItem as `Any`, so it can be downcast to its concrete type, e.g.
+    /// `menu.get_cloned_list()[0].as_any().downcast_ref::<TextItem>()`
+    fn as_any(&self) -> &dyn Any;
+    /// Get the InterfaceItem as mutable `Any`, so it can be downcast to its concrete type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
 impl<T: 'static + InterfaceItem + Clone> InterfaceItemClone for T {
     fn clone_box(&self) -> Box<dyn InterfaceItem> {
         Box::new(self.clone())
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
 
 /// The base for all `interaceItem`s. Contains metadata that is handled similarily in each `InterfaceItem`
@@ -315,6 +433,8 @@ pub struct InterfaceItemBase {
     x: u32,
     y: u32,
     focused: bool,
+    hovered: bool,
+    visible: bool,
 }
 
 impl InterfaceItemBase {
@@ -328,6 +448,8 @@ impl InterfaceItemBase {
             x: 0,
             y: 0,
             focused: false,
+            hovered: false,
+            visible: true,
         }
     }
 
@@ -355,6 +477,37 @@ impl InterfaceItemBase {
         }
         self.focused = focused;
     }
+
+    /// Whether the mouse cursor is on top of the `InterfaceItem`. Set by `Menu`, independent of focus.
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Set whether the mouse cursor is on top of the `InterfaceItem`
+    pub fn set_hovered(&mut self, hovered: bool) {
+        if hovered != self.hovered {
+            self.dirty = true;
+        }
+        self.hovered = hovered;
+    }
+
+    /// Whether the `InterfaceItem` is visible. Invisible items are not drawn by a `Menu`, take no space in it and can not be selected.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the `InterfaceItem` without removing it from the `MenuList`
+    pub fn set_visible(&mut self, visible: bool) {
+        if visible != self.visible {
+            self.dirty = true;
+        }
+        self.visible = visible;
+    }
+
+    /// Whether the `InterfaceItem` can currently be selected in a `Menu`, ie. it can be focused and is visible
+    pub(crate) fn is_selectable(&self) -> bool {
+        self.can_be_focused && self.visible
+    }
 }
 
 /// Represents a list of characters that is used to filter which character are registered in a [`TextInput`](struct.TextInput.html).
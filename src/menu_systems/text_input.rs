@@ -1,9 +1,9 @@
-use super::{Filter, InterfaceItem, InterfaceItemBase};
+use super::{Filter, InterfaceItem, InterfaceItemBase, Theme};
 
 use std::iter::repeat;
 
-use crate::text_processing::{Processable, ProcessedChar, TextProcessor};
-use crate::{Events, MouseButton, TextBuffer, TextStyle, VirtualKeyCode};
+use crate::text_processing::{OptTextStyle, Processable, ProcessedChar, TextProcessor};
+use crate::{Color, Events, MouseButton, TextBuffer, TextStyle, VirtualKeyCode};
 
 /// Represents a text-input field, that can be focused, takes in events (keyboard events as text),
 /// and it's possible to get the input text with get_text
@@ -27,6 +27,10 @@ pub struct TextInput {
     pub unfocused_style: TextStyle,
     /// Style of this TextInput when it is focused
     pub focused_style: TextStyle,
+    /// Style of the selected characters in this TextInput
+    pub selection_style: TextStyle,
+    /// Foreground color of the placeholder text. The background is the same as the rest of the TextInput.
+    pub placeholder_color: Color,
 
     /// The keyboard inputs that trigger `was_just_pressed`
     pub button_press_inputs: Vec<VirtualKeyCode>,
@@ -42,6 +46,7 @@ pub struct TextInput {
     text: String,
     prefix: String,
     suffix: String,
+    placeholder: String,
 
     processed_text: Vec<ProcessedChar>,
     needs_processing: bool,
@@ -60,6 +65,21 @@ pub struct TextInput {
     pub caret: f32,
     caret_timer: f32,
     caret_showing: bool,
+    caret_idx: usize,
+
+    selection: Option<(usize, usize)>,
+    // Index of the first text character in processed_text
+    text_offset: usize,
+
+    history_size: u32,
+    history: Vec<String>,
+    // Index of the history entry currently being shown, None if editing new text
+    history_idx: Option<usize>,
+    // The new text that was being edited before browsing the history
+    history_draft: String,
+    submit_on_press: bool,
+    // The text that was taken by pressing a button during the last handle_events
+    submitted_text: Option<String>,
 }
 
 impl TextInput {
@@ -87,6 +107,12 @@ impl TextInput {
                 fg_color: [0.2, 0.2, 0.2, 1.0],
                 ..Default::default()
             },
+            selection_style: TextStyle {
+                bg_color: [0.2, 0.2, 0.2, 1.0],
+                fg_color: [0.8, 0.8, 0.8, 1.0],
+                ..Default::default()
+            },
+            placeholder_color: [0.5, 0.5, 0.5, 1.0],
 
             base: InterfaceItemBase::new(true),
             min_width: actual_min_width,
@@ -96,6 +122,7 @@ impl TextInput {
             text: String::new(),
             prefix: String::new(),
             suffix: String::new(),
+            placeholder: String::new(),
             filter: Filter::empty_filter(),
 
             processed_text: Vec::new(),
@@ -110,12 +137,21 @@ impl TextInput {
             caret: 0.5,
             caret_timer: 0.0,
             caret_showing: false,
+            caret_idx: 0,
+
+            selection: None,
+            text_offset: 0,
+
+            history_size: 0,
+            history: Vec::new(),
+            history_idx: None,
+            history_draft: String::new(),
+            submit_on_press: false,
+            submitted_text: None,
         }
     }
 
-    with_base!(TextInput);
-    with_set_pressable!(TextInput);
-    with_style!(TextInput);
+    with_interface_item!(TextInput);
 
     /// Sets the width of the TextInput.
     pub fn with_width<T: Into<Option<u32>>, U: Into<Option<u32>>>(
@@ -140,6 +176,8 @@ impl TextInput {
     pub fn with_text<T: Into<String>>(mut self, text: T) -> TextInput {
         self.text = text.into();
         self.text_width = self.text.chars().count() as u32;
+        self.caret_idx = self.text_width as usize;
+        self.selection = None;
         self
     }
 
@@ -155,6 +193,14 @@ impl TextInput {
         self
     }
 
+    /// Sets the placeholder text of the TextInput, that is shown dimmed (see `placeholder_color`) while the TextInput is empty.
+    ///
+    /// The placeholder is only shown, [`get_text`](#method.get_text) still returns an empty text.
+    pub fn with_placeholder<T: Into<String>>(mut self, placeholder: T) -> TextInput {
+        self.placeholder = placeholder.into();
+        self
+    }
+
     /// Sets the filter for the TextInput.
     pub fn with_filter(mut self, filter: Filter) -> TextInput {
         self.filter = filter;
@@ -169,12 +215,34 @@ impl TextInput {
         self
     }
 
+    /// Sets the style of the selected characters. By default the focused style inverted.
+    pub fn with_selection_style(mut self, style: TextStyle) -> TextInput {
+        self.selection_style = style;
+        self
+    }
+
     /// Limtis the amount of characters that the TextInput will accept.
     pub fn with_character_limit<T: Into<Option<u32>>>(mut self, char_limit: T) -> TextInput {
         self.character_limit = char_limit.into();
         self
     }
 
+    /// Sets how many entries taken with [`take_text`](#method.take_text) are kept in the history.
+    ///
+    /// When the history is enabled, the entries can be recalled with up and down arrows when the TextInput is focused.
+    /// Default is 0, meaning no history is kept.
+    pub fn with_history(mut self, size: u32) -> TextInput {
+        self.set_history(size);
+        self
+    }
+
+    /// Sets whether pressing any of the `button_press_inputs` submits the text, taking it with [`take_text`](#method.take_text)
+    /// (see [`get_submitted_text`](#method.get_submitted_text)). Useful for chat or console inputs. Default is false.
+    pub fn with_submit_on_press(mut self, submit_on_press: bool) -> TextInput {
+        self.submit_on_press = submit_on_press;
+        self
+    }
+
     /// Sets the width of the TextInput.
     pub fn set_width<T: Into<Option<u32>>, U: Into<Option<u32>>>(
         mut self,
@@ -205,6 +273,15 @@ impl TextInput {
     pub fn set_text<T: Into<String>>(&mut self, text: T) {
         self.text = text.into();
         self.text_width = self.text.chars().count() as u32;
+        self.caret_idx = self.text_width as usize;
+        self.selection = None;
+        self.needs_processing = true;
+        self.base.dirty = true;
+    }
+
+    /// Sets the placeholder text of the TextInput, that is shown dimmed (see `placeholder_color`) while the TextInput is empty.
+    pub fn set_placeholder<T: Into<String>>(&mut self, placeholder: T) {
+        self.placeholder = placeholder.into();
         self.needs_processing = true;
         self.base.dirty = true;
     }
@@ -214,6 +291,179 @@ impl TextInput {
         self.text.clone()
     }
 
+    /// Returns the current text and clears the input, adding the text to the history if it is enabled.
+    ///
+    /// Useful for submitting the text, for example:
+    /// ```
+    /// use glerminal::menu_systems::TextInput;
+    ///
+    /// let mut input = TextInput::new(None, 20);
+    /// // ...
+    /// if input.was_just_pressed() {
+    ///     println!("Submitted {}", input.take_text());
+    /// }
+    /// ```
+    pub fn take_text(&mut self) -> String {
+        let text = self.text.clone();
+        self.set_text("");
+        self.history_idx = None;
+        self.history_draft = String::new();
+
+        if self.history_size > 0 && !text.is_empty() {
+            self.history.push(text.clone());
+            let overflow = self
+                .history
+                .len()
+                .saturating_sub(self.history_size as usize);
+            self.history.drain(..overflow);
+        }
+        text
+    }
+
+    /// Sets how many entries taken with [`take_text`](#method.take_text) are kept in the history.
+    ///
+    /// When the history is enabled, the entries can be recalled with up and down arrows when the TextInput is focused.
+    /// Set 0 to disable the history.
+    pub fn set_history(&mut self, size: u32) {
+        self.history_size = size;
+        let overflow = self.history.len().saturating_sub(size as usize);
+        self.history.drain(..overflow);
+        self.history_idx = None;
+    }
+
+    /// Sets whether pressing any of the `button_press_inputs` submits the text, taking it with [`take_text`](#method.take_text)
+    /// (see [`get_submitted_text`](#method.get_submitted_text)).
+    pub fn set_submit_on_press(&mut self, submit_on_press: bool) {
+        self.submit_on_press = submit_on_press;
+    }
+
+    /// Returns the text that was submitted by pressing any of the `button_press_inputs` during the last `handle_events`, if any.
+    ///
+    /// Text is only submitted when submitting on press is enabled (see [`set_submit_on_press`](#method.set_submit_on_press)).
+    /// The submitted text is taken with [`take_text`](#method.take_text), so the input is cleared and the text is added to the history, if it is enabled.
+    pub fn get_submitted_text(&self) -> Option<String> {
+        self.submitted_text.clone()
+    }
+
+    /// Returns the history of texts taken with [`take_text`](#method.take_text), oldest first.
+    pub fn get_history(&self) -> Vec<String> {
+        self.history.clone()
+    }
+
+    /// Returns the current selection as (start, end) character indices, end being exclusive.
+    ///
+    /// The selection can be made by holding shift and pressing left or right.
+    pub fn get_selection(&self) -> Option<(usize, usize)> {
+        self.selection
+    }
+
+    /// Returns the currently selected text, if anything is selected.
+    pub fn get_selected_text(&self) -> Option<String> {
+        if let Some((start, end)) = self.selection {
+            Some(self.text.chars().skip(start).take(end - start).collect())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position of the caret as a character index
+    pub fn get_caret_idx(&self) -> usize {
+        self.caret_idx
+    }
+
+    /// Moves the caret left or right, extending the selection if `select` is true.
+    /// Returns whether the caret moved.
+    fn move_caret(&mut self, left: bool, select: bool) -> bool {
+        let len = self.text_width as usize;
+        if !select {
+            if let Some((start, end)) = self.selection.take() {
+                self.caret_idx = if left { start } else { end };
+                return true;
+            }
+        }
+
+        let new_idx = if left && self.caret_idx > 0 {
+            self.caret_idx - 1
+        } else if !left && self.caret_idx < len {
+            self.caret_idx + 1
+        } else {
+            return false;
+        };
+
+        if select {
+            let anchor = match self.selection {
+                Some((start, end)) if start == self.caret_idx => end,
+                Some((start, _)) => start,
+                None => self.caret_idx,
+            };
+            self.selection = if anchor == new_idx {
+                None
+            } else {
+                Some((anchor.min(new_idx), anchor.max(new_idx)))
+            };
+        }
+        self.caret_idx = new_idx;
+        true
+    }
+
+    /// Removes the selected text, if any, and moves the caret to where the selection started.
+    fn remove_selection(&mut self) -> bool {
+        if let Some((start, end)) = self.selection.take() {
+            self.text = self
+                .text
+                .chars()
+                .take(start)
+                .chain(self.text.chars().skip(end))
+                .collect();
+            self.caret_idx = start;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves to an older (`older` = true) or newer entry in the history, replacing the current text.
+    /// Does nothing at the ends of the history.
+    fn browse_history(&mut self, older: bool) {
+        let new_idx = match (self.history_idx, older) {
+            (None, true) if !self.history.is_empty() => {
+                self.history_draft = self.text.clone();
+                Some(self.history.len() - 1)
+            }
+            (Some(idx), true) if idx > 0 => Some(idx - 1),
+            (Some(idx), false) if idx + 1 < self.history.len() => Some(idx + 1),
+            (Some(_), false) => None,
+            _ => return,
+        };
+        self.history_idx = new_idx;
+        let text = match new_idx {
+            Some(idx) => self.history[idx].clone(),
+            None => self.history_draft.clone(),
+        };
+        self.set_text(text);
+    }
+
+    fn placeholder_showing(&self) -> bool {
+        self.text.is_empty() && !self.placeholder.is_empty()
+    }
+
+    /// Width of the text that is shown, meaning the placeholder if it is showing
+    fn shown_width(&self) -> u32 {
+        if self.placeholder_showing() {
+            self.placeholder.chars().count() as u32
+        } else {
+            self.text_width
+        }
+    }
+
+    fn byte_idx(&self, char_idx: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_idx)
+            .map(|(idx, _)| idx)
+            .unwrap_or_else(|| self.text.len())
+    }
+
     #[cfg(test)]
     pub fn caret_showing(&self) -> bool {
         self.caret_showing
@@ -234,9 +484,9 @@ impl InterfaceItem for TextInput {
         if let Some(max_width) = self.max_width {
             text_width = max_width
         } else if let Some(min_width) = self.min_width {
-            text_width = self.text_width.max(min_width);
+            text_width = self.shown_width().max(min_width);
         } else {
-            text_width = self.text_width as u32;
+            text_width = self.shown_width();
         }
         (self.prefix.chars().count() + self.suffix.chars().count()) as u32 + text_width
     }
@@ -259,6 +509,7 @@ impl InterfaceItem for TextInput {
 
     fn handle_events(&mut self, events: &Events) -> bool {
         self.was_just_pressed = false;
+        self.submitted_text = None;
 
         let mut handled = false;
         if self.base.is_focused() {
@@ -274,17 +525,47 @@ impl InterfaceItem for TextInput {
                     break;
                 }
             }
+            if self.was_just_pressed && self.submit_on_press {
+                self.submitted_text = Some(self.take_text());
+                handled = true;
+            }
+            let select = events.keyboard.is_pressed(VirtualKeyCode::LShift)
+                || events.keyboard.is_pressed(VirtualKeyCode::RShift);
+            for (button, left) in &[(VirtualKeyCode::Left, true), (VirtualKeyCode::Right, false)] {
+                if events.keyboard.was_just_pressed(*button) && self.move_caret(*left, select) {
+                    self.base.dirty = true;
+                    self.needs_processing = true;
+                    handled = true;
+                }
+            }
+            if self.history_size > 0 {
+                for (button, older) in &[(VirtualKeyCode::Up, true), (VirtualKeyCode::Down, false)]
+                {
+                    // The arrows are consumed even at the ends of the history, so they never move the menu instead
+                    if events.keyboard.was_just_pressed(*button) {
+                        self.browse_history(*older);
+                        handled = true;
+                    }
+                }
+            }
             for character in events.chars.get_chars() {
                 if character == '\u{8}' {
                     // Backspace
-                    self.text.pop();
-                }
-
-                if (self.character_limit.is_none()
-                    || self.character_limit.unwrap() > self.text_width)
-                    && self.filter.has(character)
-                {
-                    self.text.push(character);
+                    if !self.remove_selection() && self.caret_idx > 0 {
+                        self.caret_idx -= 1;
+                        let idx = self.byte_idx(self.caret_idx);
+                        self.text.remove(idx);
+                    }
+                } else if self.filter.has(character) {
+                    // Typing replaces the selection
+                    self.remove_selection();
+                    if self.character_limit.is_none()
+                        || self.character_limit.unwrap() > self.text.chars().count() as u32
+                    {
+                        let idx = self.byte_idx(self.caret_idx);
+                        self.text.insert(idx, character);
+                        self.caret_idx += 1;
+                    }
                 }
 
                 self.base.dirty = true;
@@ -321,30 +602,43 @@ impl InterfaceItem for TextInput {
             } else {
                 text_w_offset = 0
             }
-            let space_offset = if self.caret_showing { 1 } else { 0 };
 
+            let shown_width = self.shown_width();
             let text_width;
             let field_width;
             if let (Some(min_width), Some(max_width)) = (self.min_width, self.max_width) {
                 // Max width and min width
-                text_width = (max_width - text_w_offset).min(self.text_width);
-                field_width = min_width.max(self.text_width).min(max_width);
+                text_width = (max_width - text_w_offset).min(shown_width);
+                field_width = min_width.max(shown_width).min(max_width);
             } else if let Some(min_width) = self.min_width {
                 // Only min width
-                text_width = self.text_width;
-                field_width = min_width.max(self.text_width + text_w_offset);
+                text_width = shown_width;
+                field_width = min_width.max(shown_width + text_w_offset);
             } else if let Some(max_width) = self.max_width {
                 // Only max width
-                text_width = (max_width - text_w_offset).min(self.text_width);
-                field_width = max_width.min(self.text_width + 1);
+                text_width = (max_width - text_w_offset).min(shown_width);
+                field_width = max_width.min(shown_width + 1);
             } else {
                 // Neither
-                text_width = self.text_width;
-                field_width = (self.text_width + text_w_offset).max(1);
+                text_width = shown_width;
+                field_width = (shown_width + text_w_offset).max(1);
             }
 
-            let mut text: String = self.text.chars().take(text_width as usize).collect();
-            if self.caret_showing {
+            // The caret is drawn after the text, or by inverting the colors of the character it is on.
+            let caret_at_end = self.caret_idx as u32 >= text_width;
+            let space_offset = if self.caret_showing && caret_at_end {
+                1
+            } else {
+                0
+            };
+
+            let shown_text = if self.placeholder_showing() {
+                &self.placeholder
+            } else {
+                &self.text
+            };
+            let mut text: String = shown_text.chars().take(text_width as usize).collect();
+            if self.caret_showing && caret_at_end {
                 text.push('_');
             }
 
@@ -353,13 +647,64 @@ impl InterfaceItem for TextInput {
                 .collect();
             let text = text + &*spaces;
 
+            self.text_offset = processor.process(vec![self.prefix.clone().into()]).len();
             self.processed_text = processor.process(vec![
                 self.prefix.clone().into(),
                 Processable::NoProcess(text),
                 self.suffix.clone().into(),
             ]);
 
+            if self.placeholder_showing() {
+                for idx in 0..text_width as usize {
+                    if let Some(c) = self.processed_text.get_mut(self.text_offset + idx) {
+                        c.style.fg_color = Some(self.placeholder_color);
+                    }
+                }
+            }
+
+            if let Some((start, end)) = self.selection {
+                let selection_style = OptTextStyle {
+                    fg_color: Some(self.selection_style.fg_color),
+                    bg_color: Some(self.selection_style.bg_color),
+                    shakiness: Some(self.selection_style.shakiness),
+                };
+                for idx in start..end.min(text_width as usize) {
+                    if let Some(c) = self.processed_text.get_mut(self.text_offset + idx) {
+                        c.style = selection_style.clone();
+                    }
+                }
+            }
+
+            if self.caret_showing && !caret_at_end {
+                if let Some(c) = self
+                    .processed_text
+                    .get_mut(self.text_offset + self.caret_idx)
+                {
+                    let style = c.style.apply_to(self.focused_style);
+                    c.style = OptTextStyle {
+                        fg_color: Some(style.bg_color),
+                        bg_color: Some(style.fg_color),
+                        shakiness: Some(style.shakiness),
+                    };
+                }
+            }
+
             self.needs_processing = false;
         }
     }
+
+    fn apply_theme(&mut self, theme: &Theme) {
+        if self.unfocused_style != theme.text
+            || self.focused_style != theme.focused
+            || self.selection_style != theme.accent
+            || self.placeholder_color != theme.disabled.fg_color
+        {
+            self.unfocused_style = theme.text;
+            self.focused_style = theme.focused;
+            self.selection_style = theme.accent;
+            self.placeholder_color = theme.disabled.fg_color;
+            self.needs_processing = true;
+            self.base.dirty = true;
+        }
+    }
 }
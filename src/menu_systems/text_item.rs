@@ -1,4 +1,4 @@
-use super::{InterfaceItem, InterfaceItemBase};
+use super::{InterfaceItem, InterfaceItemBase, Theme};
 use crate::text_processing::{ProcessedChar, TextProcessor};
 use crate::{Events, MouseButton, TextBuffer, TextStyle, VirtualKeyCode};
 
@@ -67,9 +67,7 @@ impl TextItem {
         }
     }
 
-    with_base!(TextItem);
-    with_set_pressable!(TextItem);
-    with_style!(TextItem);
+    with_interface_item!(TextItem);
 
     /// Sets the initial max width of the TextItem
     pub fn with_max_width(mut self, max_width: u32) -> TextItem {
@@ -178,4 +176,12 @@ impl InterfaceItem for TextItem {
             self.needs_processing = false;
         }
     }
+
+    fn apply_theme(&mut self, theme: &Theme) {
+        if self.unfocused_style != theme.text || self.focused_style != theme.focused {
+            self.unfocused_style = theme.text;
+            self.focused_style = theme.focused;
+            self.base.dirty = true;
+        }
+    }
 }
@@ -0,0 +1,68 @@
+use crate::TextStyle;
+
+/// Represents a palette of styles that can be shared by all items in a [`Menu`](struct.Menu.html), see [`Menu::with_theme`](struct.Menu.html#method.with_theme).
+///
+/// The built-in items use the theme like this:
+/// - `text` is used when the item is unfocused, and `focused` when it is focused.
+/// - `accent` is used for the selection of a [TextInput](struct.TextInput.html) and the key of a [KeyHint](struct.KeyHint.html).
+/// - `disabled` is used for the placeholder of a [TextInput](struct.TextInput.html).
+/// - `background` is not used by the items, but can be used for ie. the background of a [Window](struct.Window.html).
+///
+/// Custom items can use the theme by implementing [`InterfaceItem::apply_theme`](trait.InterfaceItem.html#method.apply_theme).
+///
+/// Example:
+/// ```
+/// use glerminal::menu_systems::{Menu, Theme};
+/// use glerminal::TextStyle;
+///
+/// let theme = Theme {
+///     focused: TextStyle::warning(),
+///     ..Default::default()
+/// };
+/// let menu = Menu::new().with_theme(theme);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    /// Style of backgrounds, such as the insides of windows
+    pub background: TextStyle,
+    /// Style of unfocused items
+    pub text: TextStyle,
+    /// Style of focused items
+    pub focused: TextStyle,
+    /// Style of highlighted parts of items
+    pub accent: TextStyle,
+    /// Style of inactive or dimmed parts of items
+    pub disabled: TextStyle,
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme {
+            background: TextStyle {
+                bg_color: [0.0, 0.0, 0.0, 1.0],
+                ..Default::default()
+            },
+            text: TextStyle {
+                bg_color: [0.0, 0.0, 0.0, 0.0],
+                fg_color: [0.8, 0.8, 0.8, 1.0],
+                ..Default::default()
+            },
+            focused: TextStyle {
+                bg_color: [0.8, 0.8, 0.8, 1.0],
+                fg_color: [0.2, 0.2, 0.2, 1.0],
+                ..Default::default()
+            },
+            accent: TextStyle {
+                bg_color: [0.2, 0.4, 0.8, 1.0],
+                fg_color: [1.0, 1.0, 1.0, 1.0],
+                ..Default::default()
+            },
+            disabled: TextStyle {
+                bg_color: [0.0, 0.0, 0.0, 0.0],
+                fg_color: [0.5, 0.5, 0.5, 1.0],
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -1,73 +1,5 @@
-use crate::text_buffer::{Color, TextBuffer, TextStyle};
-
-/// Represents all the different characters that are used in drawing the border for `Window`
-pub struct BorderChars {
-    /// The top left corner piece. Default is ╔
-    pub top_left: char,
-    /// The bottom left corner piece. Default is ╚
-    pub bottom_left: char,
-    /// The top right corner piece. Default is ╗
-    pub top_right: char,
-    /// The bottom right corner piece. Default is ╝
-    pub bottom_right: char,
-
-    /// A vertical line for non-corner borders and splits. Default is ║
-    pub vertical_line: char,
-    /// A horizontal line for non-corner borders and splits. Default is ═
-    pub horizontal_line: char,
-
-    /// The topmost character for a horizontal split. Default is ╦
-    pub top_split: char,
-    /// The bottom-most character for a horizontal split. Default is ╩
-    pub bottom_split: char,
-    /// The leftmost-most character for a vertial split. Default is ╠
-    pub left_split: char,
-    /// The rightmost-most character for a vertial split. Default is ╣
-    pub right_split: char,
-    /// The middle character where two splits intersect. Default is ╬
-    pub middle_split: char,
-}
-
-impl BorderChars {
-    /// Make BorderChars where every field is a spacebar.
-    pub fn empty() -> BorderChars {
-        BorderChars {
-            top_left: ' ',
-            bottom_left: ' ',
-            top_right: ' ',
-            bottom_right: ' ',
-
-            vertical_line: ' ',
-            horizontal_line: ' ',
-
-            top_split: ' ',
-            bottom_split: ' ',
-            left_split: ' ',
-            right_split: ' ',
-            middle_split: ' ',
-        }
-    }
-}
-
-impl Default for BorderChars {
-    fn default() -> BorderChars {
-        BorderChars {
-            top_left: '╔',
-            bottom_left: '╚',
-            top_right: '╗',
-            bottom_right: '╝',
-
-            vertical_line: '║',
-            horizontal_line: '═',
-
-            top_split: '╦',
-            bottom_split: '╩',
-            left_split: '╠',
-            right_split: '╣',
-            middle_split: '╬',
-        }
-    }
-}
+use crate::text_buffer::{BorderChars, Color, TextBuffer, TextStyle};
+use crate::{Events, MouseButton};
 
 /// Represents a window that clears everything in it's way and is able to limit the cursor within it's bounds with `set_limits`.
 ///
@@ -194,6 +126,16 @@ impl Window {
         self.y = y;
     }
 
+    /// Returns the position of the window, meaning the position of the top left corner of the border.
+    pub fn get_pos(&self) -> (u32, u32) {
+        (self.x, self.y)
+    }
+
+    /// Returns the position of the top left corner of the interior of the window, inside the borders.
+    pub fn inner_pos(&self) -> (u32, u32) {
+        (self.x + 1, self.y + 1)
+    }
+
     /// Add a vertical split to the given index. Lowest index is 0, and highest is width.
     /// A 3x3 window with vertical split at idx 1 looks like this:
     ///  ╔═╦═╗
@@ -289,6 +231,57 @@ impl Window {
         );
     }
 
+    /// Sets the width and height of the window (not including the borders), removing any splits that no longer fit inside it.
+    pub fn resize_to(&mut self, new_size: (u32, u32)) {
+        let (width, height) = new_size;
+        self.width = width.max(1);
+        self.height = height.max(1);
+        let (width, height) = (self.width, self.height);
+        self.vertical_splits.retain(|split| *split <= width);
+        self.horizontal_splits.retain(|split| *split <= height);
+    }
+
+    /// Returns whether the left mouse button was just pressed on the bottom right corner of the window,
+    /// meaning that the user started dragging the corner to resize the window.
+    ///
+    /// The Window does not keep track of the dragging itself. While the left mouse button is held after this,
+    /// the new size can be get with [`drag_size`](#method.drag_size) and applied with [`resize_to`](#method.resize_to):
+    /// ```
+    /// # use glerminal::{TerminalBuilder, TextBuffer, MouseButton};
+    /// # use glerminal::menu_systems::Window;
+    /// # let terminal = TerminalBuilder::new().with_headless(true).build();
+    /// # let mut text_buffer = TextBuffer::create(&terminal, (10, 10)).unwrap();
+    /// let mut window = Window::new(3, 3);
+    /// let mut dragging = false;
+    ///
+    /// let events = terminal.get_current_events();
+    /// if window.corner_hit(&text_buffer, &events) {
+    ///     dragging = true;
+    /// } else if !events.mouse.is_pressed(MouseButton::Left) {
+    ///     dragging = false;
+    /// }
+    /// if dragging {
+    ///     if let Some(size) = window.drag_size(&text_buffer, &events) {
+    ///         window.resize_to(size);
+    ///     }
+    /// }
+    /// ```
+    pub fn corner_hit(&self, text_buffer: &TextBuffer, events: &Events) -> bool {
+        events.mouse.was_just_pressed(MouseButton::Left)
+            && events.cursor.get_location(text_buffer)
+                == Some((self.x + self.width + 1, self.y + self.height + 1))
+    }
+
+    /// Returns the size the window would have if its bottom right corner was moved to the cursor's location,
+    /// or None if the cursor is not on the TextBuffer. The size is at least 1x1.
+    pub fn drag_size(&self, text_buffer: &TextBuffer, events: &Events) -> Option<(u32, u32)> {
+        let (x, y) = events.cursor.get_location(text_buffer)?;
+        Some((
+            x.saturating_sub(self.x + 1).max(1),
+            y.saturating_sub(self.y + 1).max(1),
+        ))
+    }
+
     /// Set limits for the TextBuffer so that nothing can be written outside the window.
     pub fn set_limits(&self, text_buffer: &mut TextBuffer) {
         text_buffer.cursor.set_limits(
@@ -4,8 +4,6 @@ use super::{Program, Renderable, Texture, Vao, Vbo};
 use crate::text_buffer::TextBuffer;
 
 pub(crate) struct BackgroundMesh {
-    width: u32,
-    height: u32,
     vbo_pos: Vbo,
     vbo_col: Vbo,
     vao: Vao,
@@ -67,8 +65,6 @@ impl BackgroundMesh {
         let count = (width * height * 6) as i32;
 
         BackgroundMesh {
-            width: width,
-            height: height,
             vbo_pos: vbo_pos,
             vbo_col: vbo_col,
             vao: vao,
@@ -77,55 +73,66 @@ impl BackgroundMesh {
     }
 
     pub fn update(&self, text_buffer: &TextBuffer) {
-        if (text_buffer.height * text_buffer.width) as usize != text_buffer.chars.len() {
-            panic!("Given TextBuffer height/width do not math chars.len()");
-        }
-
-        // Create new color vertex buffer
-        let mut vertex_buffer_pos: Vec<f32> = Vec::new();
+        let (vertex_buffer_pos, vertex_buffer_col) = create_vertex_buffers(text_buffer);
 
-        // Create new color vertex buffer
-        let mut vertex_buffer_col: Vec<f32> = Vec::new();
-
-        let character_width = 1.0 / self.width as f32;
-        let character_height = 1.0 / self.height as f32;
-        for y in 0..text_buffer.height {
-            for x in 0..text_buffer.width {
-                let character = text_buffer.get_character(x, y).unwrap();
+        self.count.set((vertex_buffer_pos.len() * 6) as i32);
 
-                if character.style.bg_color == [0.0; 4] {
-                    continue;
-                }
+        super::upload_buffer(self.vbo_pos, &vertex_buffer_pos);
+        super::upload_buffer(self.vbo_col, &vertex_buffer_col);
+    }
+}
 
-                // New Vertex Buffers
-                let x_off = x as f32 * character_width;
-                let y_off = y as f32 * character_height;
-                let mut single_character_vbuff = vec![
-                    x_off,
-                    y_off + character_height,
-                    x_off + character_width,
-                    y_off + character_height,
-                    x_off,
-                    y_off,
-                    x_off + character_width,
-                    y_off,
-                    x_off,
-                    y_off,
-                    x_off + character_width,
-                    y_off + character_height,
-                ];
-                vertex_buffer_pos.append(&mut single_character_vbuff);
+/// Creates the position and color vertex buffers of the backgrounds in a TextBuffer
+pub(crate) fn create_vertex_buffers(text_buffer: &TextBuffer) -> (Vec<f32>, Vec<f32>) {
+    if (text_buffer.height * text_buffer.width) as usize != text_buffer.chars.len() {
+        panic!("Given TextBuffer height/width do not math chars.len()");
+    }
 
-                // Get colors
-                for _ in 0..6 {
-                    vertex_buffer_col.append(&mut character.style.bg_color.to_vec());
-                }
+    // Create new color vertex buffer
+    let mut vertex_buffer_pos: Vec<f32> = Vec::new();
+
+    // Create new color vertex buffer
+    let mut vertex_buffer_col: Vec<f32> = Vec::new();
+
+    let character_width = 1.0 / text_buffer.width as f32;
+    let character_height = 1.0 / text_buffer.height as f32;
+    for y in 0..text_buffer.height {
+        for x in 0..text_buffer.width {
+            let character = text_buffer.get_character(x, y).unwrap();
+
+            let bg_color = if character.has_visible_background() {
+                character.style.bg_color
+            } else if text_buffer.opaque_background {
+                text_buffer.clear_color
+            } else {
+                continue;
+            };
+
+            // New Vertex Buffers
+            let x_off = x as f32 * character_width;
+            let y_off = y as f32 * character_height;
+            let mut single_character_vbuff = vec![
+                x_off,
+                y_off + character_height,
+                x_off + character_width,
+                y_off + character_height,
+                x_off,
+                y_off,
+                x_off + character_width,
+                y_off,
+                x_off,
+                y_off,
+                x_off + character_width,
+                y_off + character_height,
+            ];
+            vertex_buffer_pos.append(&mut single_character_vbuff);
+
+            // Get colors
+            for _ in 0..6 {
+                vertex_buffer_col.append(&mut bg_color.to_vec());
             }
         }
-
-        self.count.set((vertex_buffer_pos.len() * 6) as i32);
-
-        super::upload_buffer(self.vbo_pos, &vertex_buffer_pos);
-        super::upload_buffer(self.vbo_col, &vertex_buffer_col);
     }
+
+    (vertex_buffer_pos, vertex_buffer_col)
 }
@@ -1,7 +1,10 @@
 pub(crate) mod backgroundmesh;
 pub(crate) mod textbuffermesh;
 
+use crate::terminal::BlendMode;
 use gl;
+#[cfg(test)]
+use std::cell::RefCell;
 use std::ffi::{CStr, CString};
 use std::mem;
 use std::os::raw::c_void;
@@ -16,6 +19,12 @@ pub(crate) static DEBUG_FRAG_SHADER: &'static str =
 
 pub(crate) type Matrix4 = [f32; 16];
 
+#[cfg(test)]
+thread_local! {
+    // GL version returned by get_version instead of asking the driver, so version checks can be tested without a GL context
+    static VERSION_OVERRIDE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
 pub(crate) type Vao = u32;
 pub(crate) type Vbo = u32;
 pub(crate) type Texture = u32;
@@ -32,6 +41,7 @@ pub(crate) struct Program {
 
     uniform_proj_mat: i32,
     uniform_time: i32,
+    uniform_shake_params: i32,
 }
 
 impl Program {
@@ -46,6 +56,7 @@ impl Program {
 
             uniform_proj_mat: 0,
             uniform_time: 0,
+            uniform_shake_params: 0,
         }
     }
 }
@@ -72,28 +83,110 @@ pub(crate) fn get_error(headless: bool) -> Option<u32> {
     }
 }
 
-pub(crate) fn get_version() -> String {
+pub(crate) fn get_version() -> Result<String, String> {
+    #[cfg(test)]
+    {
+        if let Some(version) = VERSION_OVERRIDE.with(|version| version.borrow().clone()) {
+            return Ok(version);
+        }
+    }
     unsafe {
-        CStr::from_ptr(gl::GetString(gl::VERSION) as *const i8)
+        let version = gl::GetString(gl::VERSION);
+        if version.is_null() {
+            return Err("Failed to get the OpenGL version from the GL driver".to_owned());
+        }
+        CStr::from_ptr(version as *const i8)
             .to_str()
-            .unwrap()
-            .to_owned()
+            .map(|version| version.to_owned())
+            .map_err(|error| format!("Invalid version value from GL driver: {}", error))
     }
 }
 
-pub(crate) fn is_gl_version_compatible(text: &str) -> bool {
-    let mut parts = text.split('.');
-    let error = &*format!("Invalid version value from GL driver: '{}'", text);
-    let major: i32 = parts.next().expect(error).parse().expect(error);
-    let minor: i32 = parts
-        .next()
-        .expect(error)
-        .split(' ')
-        .next()
-        .expect(error)
-        .parse()
-        .expect(error);
-    major > 3 || (major == 3 && minor >= 3)
+/// Makes `get_version` return the given version on this thread, or ask the driver again if None.
+#[cfg(test)]
+pub(crate) fn set_version_override(version: Option<&str>) {
+    VERSION_OVERRIDE.with(|override_version| {
+        *override_version.borrow_mut() = version.map(|version| version.to_owned())
+    });
+}
+
+/// Returns whether `get_version` has a version to return even without a GL context, only possible in tests.
+pub(crate) fn has_version_override() -> bool {
+    #[cfg(test)]
+    {
+        VERSION_OVERRIDE.with(|version| version.borrow().is_some())
+    }
+    #[cfg(not(test))]
+    {
+        false
+    }
+}
+
+/// Returns an error if the given GL version string is invalid or lower than the minimum version (major, minor)
+pub(crate) fn check_gl_version(text: &str, min_gl: (u32, u32)) -> Result<(), String> {
+    if parse_gl_version(text)? >= min_gl {
+        Ok(())
+    } else {
+        Err(format!(
+            "GL version too low: OpenGL {}, required {}.{}",
+            text, min_gl.0, min_gl.1
+        ))
+    }
+}
+
+/// Parses the (major, minor) version from a GL version string, ie. "3.3.0 Mesa 18.0.5"
+fn parse_gl_version(text: &str) -> Result<(u32, u32), String> {
+    let error = || format!("Invalid version value from GL driver: '{}'", text);
+    let mut parts = text.split(&['.', ' '][..]);
+    let mut next_number = || {
+        parts
+            .next()
+            .and_then(|part| part.parse().ok())
+            .ok_or_else(error)
+    };
+    let major = next_number()?;
+    let minor = next_number()?;
+    Ok((major, minor))
+}
+
+/// Enables OpenGL debug output, printing the messages to stderr. Returns false if the driver does not support it.
+pub(crate) fn enable_debug_output() -> bool {
+    if !gl::DebugMessageCallback::is_loaded() {
+        return false;
+    }
+    unsafe {
+        gl::Enable(gl::DEBUG_OUTPUT);
+        gl::Enable(gl::DEBUG_OUTPUT_SYNCHRONOUS);
+        gl::DebugMessageCallback(debug_callback, ptr::null());
+    }
+    true
+}
+
+extern "system" fn debug_callback(
+    _source: gl::types::GLenum,
+    _gltype: gl::types::GLenum,
+    id: gl::types::GLuint,
+    severity: gl::types::GLenum,
+    _length: gl::types::GLsizei,
+    message: *const gl::types::GLchar,
+    _user_param: *mut c_void,
+) {
+    if message.is_null() {
+        return;
+    }
+    let severity = match severity {
+        gl::DEBUG_SEVERITY_HIGH => "high",
+        gl::DEBUG_SEVERITY_MEDIUM => "medium",
+        gl::DEBUG_SEVERITY_LOW => "low",
+        _ => "notification",
+    };
+    let message = unsafe { CStr::from_ptr(message) };
+    eprintln!(
+        "GL debug ({}, id {}): {}",
+        severity,
+        id,
+        message.to_string_lossy()
+    );
 }
 
 pub(crate) fn clear() {
@@ -109,6 +202,76 @@ pub(crate) fn update_viewport(dimensions: (i32, i32)) {
     }
 }
 
+/// Restricts drawing to the given rectangle (x, y, width, height) in pixels, with the origin at the bottom left.
+/// None removes the restriction.
+pub(crate) fn set_scissor(rect: Option<(i32, i32, i32, i32)>) {
+    unsafe {
+        if let Some((x, y, width, height)) = rect {
+            gl::Enable(gl::SCISSOR_TEST);
+            gl::Scissor(x, y, width, height);
+        } else {
+            gl::Disable(gl::SCISSOR_TEST);
+        }
+    }
+}
+
+/// Returns the source and destination factors for `gl::BlendFunc` for the given `BlendMode`
+pub(crate) fn blend_factors(blend_mode: BlendMode) -> (gl::types::GLenum, gl::types::GLenum) {
+    match blend_mode {
+        BlendMode::Alpha => (gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA),
+        BlendMode::Additive => (gl::SRC_ALPHA, gl::ONE),
+        BlendMode::Multiply => (gl::DST_COLOR, gl::ZERO),
+    }
+}
+
+pub(crate) fn set_blend_mode(blend_mode: BlendMode) {
+    let (src, dst) = blend_factors(blend_mode);
+    unsafe {
+        gl::BlendFunc(src, dst);
+    }
+}
+
+/// Calculates the scissor rectangle in pixels for a normalized (x, y, width, height) rectangle of the window,
+/// where (0, 0) is the top left corner.
+pub(crate) fn calc_scissor(
+    dimensions: (f32, f32),
+    rect: (f32, f32, f32, f32),
+) -> (i32, i32, i32, i32) {
+    let (width, height) = dimensions;
+    let x0 = (rect.0 * width).round() as i32;
+    let x1 = ((rect.0 + rect.2) * width).round() as i32;
+    let y0 = ((1.0 - rect.1 - rect.3) * height).round() as i32;
+    let y1 = ((1.0 - rect.1) * height).round() as i32;
+    (x0, y0, (x1 - x0).max(0), (y1 - y0).max(0))
+}
+
+/// Calculates the normalized (x, y, width, height) rectangle of the window that the given cells of a TextBuffer cover,
+/// when the TextBuffer is drawn with the given overflows and relative dimensions.
+pub(crate) fn calc_cells_rect(
+    grid: (u32, u32),
+    cells: (u32, u32, u32, u32),
+    overflows: (f32, f32),
+    relative_dimensions: (f32, f32),
+) -> (f32, f32, f32, f32) {
+    let cell_width = 1.0 / (grid.0 as f32 * relative_dimensions.0);
+    let cell_height = 1.0 / (grid.1 as f32 * relative_dimensions.1);
+    (
+        overflows.0 + cells.0 as f32 * cell_width,
+        overflows.1 + cells.1 as f32 * cell_height,
+        cells.2 as f32 * cell_width,
+        cells.3 as f32 * cell_height,
+    )
+}
+
+/// Calculates the center of a normalized (x, y, width, height) rectangle of the window in pixels,
+/// where (0, 0) is the top left corner.
+pub(crate) fn calc_rect_center(dimensions: (f32, f32), rect: (f32, f32, f32, f32)) -> (i32, i32) {
+    (
+        ((rect.0 + rect.2 / 2.0) * dimensions.0).round() as i32,
+        ((rect.1 + rect.3 / 2.0) * dimensions.1).round() as i32,
+    )
+}
+
 pub(crate) fn set_debug(debug: bool) {
     unsafe {
         if debug {
@@ -119,7 +282,13 @@ pub(crate) fn set_debug(debug: bool) {
     }
 }
 
-pub(crate) fn draw(program: Program, proj_matrix: Matrix4, time: f32, renderable: &Renderable) {
+pub(crate) fn draw(
+    program: Program,
+    proj_matrix: Matrix4,
+    time: f32,
+    shake_params: (f32, f32),
+    renderable: &Renderable,
+) {
     unsafe {
         gl::UseProgram(program.shader_program);
         if let Some(texture) = renderable.get_texture() {
@@ -130,6 +299,7 @@ pub(crate) fn draw(program: Program, proj_matrix: Matrix4, time: f32, renderable
         gl::UniformMatrix4fv(program.uniform_proj_mat, 1, gl::TRUE, proj_matrix.as_ptr());
 
         gl::Uniform1fv(program.uniform_time, 1, vec![time].as_ptr());
+        gl::Uniform2f(program.uniform_shake_params, shake_params.0, shake_params.1);
 
         gl::DrawArrays(gl::TRIANGLES, 0, renderable.get_count());
     }
@@ -139,13 +309,58 @@ pub(crate) fn create_proj_matrix(dimensions: (f32, f32), aspect_ratio: f32) -> M
     let (width, height) = dimensions;
     let true_width = height * aspect_ratio;
     let true_height = width / aspect_ratio;
-    let mut overflow_width = 0f32;
-    let mut overflow_height = 0f32;
     if true_width < width {
-        overflow_width = (width - true_width) / true_width;
+        create_area_proj_matrix(dimensions, (true_width, height))
     } else {
-        overflow_height = (height - true_height) / true_height;
+        create_area_proj_matrix(dimensions, (width, true_height))
     }
+}
+
+/// Creates a projection matrix that draws the `src` cells (x, y, width, height) of a TextBuffer of the given grid size
+/// into the normalized `dst` rectangle (x, y, width, height) of the window, where (0, 0) is the top left corner.
+pub(crate) fn create_region_proj_matrix(
+    grid: (u32, u32),
+    src: (u32, u32, u32, u32),
+    dst: (f32, f32, f32, f32),
+) -> Matrix4 {
+    let (grid_width, grid_height) = (grid.0 as f32, grid.1 as f32);
+    let src_left = src.0 as f32 / grid_width;
+    let src_right = (src.0 + src.2) as f32 / grid_width;
+    let src_top = src.1 as f32 / grid_height;
+    let src_bottom = (src.1 + src.3) as f32 / grid_height;
+
+    let dst_left = dst.0 * 2.0 - 1.0;
+    let dst_right = (dst.0 + dst.2) * 2.0 - 1.0;
+    let dst_top = 1.0 - dst.1 * 2.0;
+    let dst_bottom = 1.0 - (dst.1 + dst.3) * 2.0;
+
+    let scale_x = (dst_right - dst_left) / (src_right - src_left);
+    let scale_y = (dst_bottom - dst_top) / (src_bottom - src_top);
+    [
+        scale_x,
+        0.0,
+        0.0,
+        dst_left - scale_x * src_left,
+        0.0,
+        scale_y,
+        0.0,
+        dst_top - scale_y * src_top,
+        0.0,
+        0.0,
+        -1.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+    ]
+}
+
+/// Creates a projection matrix that draws into an area of the given size, centered in the window.
+pub(crate) fn create_area_proj_matrix(dimensions: (f32, f32), area: (f32, f32)) -> Matrix4 {
+    let (width, height) = dimensions;
+    let overflow_width = (width - area.0) / area.0;
+    let overflow_height = (height - area.1) / area.1;
     let left = 0.0 - overflow_width / 2.0;
     let top = 0.0 - overflow_height / 2.0;
     let right = 1.0 + overflow_width / 2.0;
@@ -297,6 +512,7 @@ pub(crate) fn create_program(vert_shader: &str, frag_shader: &str) -> Program {
 
             uniform_proj_mat: get_uniform_location(program, "proj_mat"),
             uniform_time: get_uniform_location(program, "time"),
+            uniform_shake_params: get_uniform_location(program, "shake_params"),
         }
     }
 }
@@ -28,6 +28,14 @@ impl Renderable for TextBufferMesh {
     }
 }
 
+/// The vertex buffers (position, color, texture coordinates and shakiness) of the characters in a TextBuffer
+pub(crate) struct VertexBuffers {
+    pub pos: Vec<f32>,
+    pub col: Vec<f32>,
+    pub tex: Vec<f32>,
+    pub shakiness: Vec<f32>,
+}
+
 impl TextBufferMesh {
     pub fn new(program: Program, dimensions: (u32, u32), font: &Font) -> TextBufferMesh {
         let (width, height) = dimensions;
@@ -59,94 +67,12 @@ impl TextBufferMesh {
     }
 
     pub fn update(&self, text_buffer: &TextBuffer, font: &Font) {
-        if (text_buffer.height * text_buffer.width) as usize != text_buffer.chars.len() {
-            panic!("Given TextBuffer height/width do not math chars.len()");
-        }
-
-        // Create new position veretex buffer
-        let mut vertex_buffer_pos: Vec<f32> = Vec::new();
-
-        // Create new color vertex buffer
-        let mut vertex_buffer_col: Vec<f32> = Vec::new();
-
-        // Create new tex coords
-        let mut vertex_buffer_tex: Vec<f32> = Vec::new();
-
-        // Create new tex coords
-        let mut vertex_buffer_shakiness: Vec<f32> = Vec::new();
-
-        // Fill those arrays
-        let character_width = 1.0 / text_buffer.width as f32;
-        let character_height = 1.0 / text_buffer.height as f32;
-
-        let default_char_data = match font.get_character('?' as u16) {
-            Ok(data) => data,
-            Err(_) => font.get_character(' ' as u16).ok().unwrap(),
-        };
-
-        for y in 0..text_buffer.height {
-            for x in 0..text_buffer.width {
-                // Calculate pos vertex coords
-                let character = text_buffer.get_character(x, y).unwrap();
-                if character.get_raw_char() == (' ' as u16) {
-                    continue;
-                }
-                let char_data = match font.get_character(character.get_raw_char()) {
-                    Ok(data) => data,
-                    Err(_) => default_char_data.clone(),
-                };
-                let width =
-                    character_width * (char_data.width as f32 / font.average_xadvance as f32);
-                let height = character_height * (char_data.height as f32 / font.line_height as f32);
-
-                let bmoffset_x =
-                    character_width * (char_data.x_off as f32 / font.average_xadvance as f32);
-                let bmoffset_y =
-                    character_height * (char_data.y_off as f32 / font.line_height as f32);
-
-                let x_off = x as f32 * character_width + bmoffset_x;
-                let y_off = y as f32 * character_height + bmoffset_y;
-                let mut single_character_vbuff = vec![
-                    x_off,
-                    y_off + height,
-                    x_off + width,
-                    y_off + height,
-                    x_off,
-                    y_off,
-                    x_off + width,
-                    y_off,
-                    x_off,
-                    y_off,
-                    x_off + width,
-                    y_off + height,
-                ];
-                vertex_buffer_pos.append(&mut single_character_vbuff);
-
-                // Color and Shakiness
-                for _ in 0..6 {
-                    vertex_buffer_col.append(&mut character.style.fg_color.to_vec());
-                    vertex_buffer_shakiness.push(character.style.shakiness);
-                }
-
-                // Calculate tex coords
-                let mut char_tex_coords = vec![
-                    char_data.x1,
-                    char_data.y2,
-                    char_data.x2,
-                    char_data.y2,
-                    char_data.x1,
-                    char_data.y1,
-                    char_data.x2,
-                    char_data.y1,
-                    char_data.x1,
-                    char_data.y1,
-                    char_data.x2,
-                    char_data.y2,
-                ];
-
-                vertex_buffer_tex.append(&mut char_tex_coords);
-            }
-        }
+        let VertexBuffers {
+            pos: vertex_buffer_pos,
+            col: vertex_buffer_col,
+            tex: vertex_buffer_tex,
+            shakiness: vertex_buffer_shakiness,
+        } = create_vertex_buffers(text_buffer, font);
 
         self.count.set((vertex_buffer_pos.len() * 6) as i32);
 
@@ -156,3 +82,107 @@ impl TextBufferMesh {
         super::upload_buffer(self.vbo_shakiness, &vertex_buffer_shakiness);
     }
 }
+
+pub(crate) fn create_vertex_buffers(text_buffer: &TextBuffer, font: &Font) -> VertexBuffers {
+    if (text_buffer.height * text_buffer.width) as usize != text_buffer.chars.len() {
+        panic!("Given TextBuffer height/width do not math chars.len()");
+    }
+
+    // Create new position veretex buffer
+    let mut vertex_buffer_pos: Vec<f32> = Vec::new();
+
+    // Create new color vertex buffer
+    let mut vertex_buffer_col: Vec<f32> = Vec::new();
+
+    // Create new tex coords
+    let mut vertex_buffer_tex: Vec<f32> = Vec::new();
+
+    // Create new tex coords
+    let mut vertex_buffer_shakiness: Vec<f32> = Vec::new();
+
+    // Fill those arrays
+    let character_width = 1.0 / text_buffer.width as f32;
+    let character_height = 1.0 / text_buffer.height as f32;
+
+    let default_char_data = match font.get_character('?' as u16) {
+        Ok(data) => data,
+        Err(_) => font.get_character(' ' as u16).ok().unwrap(),
+    };
+
+    for y in 0..text_buffer.height {
+        // Horizontal position of the next character when positioning proportionally
+        let mut pen_x = 0.0;
+        for x in 0..text_buffer.width {
+            // Calculate pos vertex coords
+            let character = text_buffer.get_character(x, y).unwrap();
+            let char_data = match font.get_character(character.get_raw_char()) {
+                Ok(data) => data,
+                Err(_) => default_char_data.clone(),
+            };
+            let cell_x = if text_buffer.proportional {
+                pen_x
+            } else {
+                x as f32 * character_width
+            };
+            pen_x += character_width * (char_data.x_advance as f32 / font.average_xadvance);
+            if character.is_space() {
+                continue;
+            }
+            let width = character_width * (char_data.width as f32 / font.average_xadvance as f32);
+            let height = character_height * (char_data.height as f32 / font.line_height as f32);
+
+            let bmoffset_x =
+                character_width * (char_data.x_off as f32 / font.average_xadvance as f32);
+            let bmoffset_y = character_height * (char_data.y_off as f32 / font.line_height as f32);
+
+            let x_off = cell_x + bmoffset_x;
+            let y_off = y as f32 * character_height + bmoffset_y;
+            let mut single_character_vbuff = vec![
+                x_off,
+                y_off + height,
+                x_off + width,
+                y_off + height,
+                x_off,
+                y_off,
+                x_off + width,
+                y_off,
+                x_off,
+                y_off,
+                x_off + width,
+                y_off + height,
+            ];
+            vertex_buffer_pos.append(&mut single_character_vbuff);
+
+            // Color and Shakiness
+            for _ in 0..6 {
+                vertex_buffer_col.append(&mut character.style.fg_color.to_vec());
+                vertex_buffer_shakiness.push(character.style.shakiness);
+            }
+
+            // Calculate tex coords
+            let mut char_tex_coords = vec![
+                char_data.x1,
+                char_data.y2,
+                char_data.x2,
+                char_data.y2,
+                char_data.x1,
+                char_data.y1,
+                char_data.x2,
+                char_data.y1,
+                char_data.x1,
+                char_data.y1,
+                char_data.x2,
+                char_data.y2,
+            ];
+
+            vertex_buffer_tex.append(&mut char_tex_coords);
+        }
+    }
+
+    VertexBuffers {
+        pos: vertex_buffer_pos,
+        col: vertex_buffer_col,
+        tex: vertex_buffer_tex,
+        shakiness: vertex_buffer_shakiness,
+    }
+}
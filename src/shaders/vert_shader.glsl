@@ -10,10 +10,12 @@ out vec4 f_color;
 
 uniform mat4 proj_mat;
 uniform float time;
+// x = amplitude, y = frequency
+uniform vec2 shake_params;
 
 void main() {
-  float x_shake = sin(time * sqrt(shakiness) * 50) * 0.02 * shakiness / 10;
-  float y_shake = sin(time * sqrt(shakiness) * 40) * 0.03 * shakiness / 10;
+  float x_shake = sin(time * sqrt(shakiness) * 50 * shake_params.y) * 0.02 * shake_params.x * shakiness / 10;
+  float y_shake = sin(time * sqrt(shakiness) * 40 * shake_params.y) * 0.03 * shake_params.x * shakiness / 10;
   gl_Position = proj_mat * vec4(position + vec2(x_shake, y_shake), 0, 1);
   f_texcoord = texcoord;
   f_color = color;
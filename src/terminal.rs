@@ -1,18 +1,31 @@
-#[allow(unused_imports)]
 use glutin::VirtualKeyCode;
 use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
 use std::time::SystemTime;
 
 use crate::display::Display;
-use crate::events::Events;
+use crate::events::{Events, TerminalEvent};
 use crate::font::Font;
-use crate::renderer::Program;
-use crate::text_buffer::TextBuffer;
-use crate::{renderer, FontFormat};
+use crate::renderer;
+use crate::renderer::{Matrix4, Program};
+use crate::text_buffer::{Color, TextBuffer};
 
+#[cfg(feature = "bundled_font")]
 static SCP_FONT: &'static str = include_str!("../fonts/source_code_pro.sfl");
+#[cfg(feature = "bundled_font")]
 static SCP_PNG: &'static [u8] = include_bytes!("../fonts/source_code_pro.png");
 
+/// The blend mode used when drawing a `TextBuffer`, see [`Terminal::draw_with_blend`](struct.Terminal.html#method.draw_with_blend).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Regular alpha blending, the default used by [`Terminal::draw`](struct.Terminal.html#method.draw).
+    Alpha,
+    /// Adds the colors on top of what has already been drawn, weighted by their alpha. Useful for glow-effects.
+    Additive,
+    /// Multiplies what has already been drawn with the colors. The alpha of the colors is ignored.
+    Multiply,
+}
+
 /// A builder for the `Terminal`. Includes some settings that can be set before building.
 ///
 /// See [terminal mod](index.html) for examples and more detailed documentation.
@@ -23,7 +36,9 @@ pub struct TerminalBuilder {
     pub dimensions: (u32, u32),
     /// The clear color of the terminal.
     pub clear_color: (f32, f32, f32, f32),
-    /// The font that the terminal uses
+    /// The font that the terminal uses. Defaults to the bundled font, which requires the `bundled_font` feature (on by default).
+    ///
+    /// Without the feature the default is an empty font, and a font must be given with [`with_font`](#method.with_font).
     pub font: Font,
     /// Is the terminal visible/does it open when builded. It can be later opened/shown with [`show`](struct.Terminal.html#method.show)
     pub visibility: bool,
@@ -39,6 +54,22 @@ pub struct TerminalBuilder {
     pub text_buffer_aspect_ratio: bool,
     /// Enable/Disable Vsync
     pub vsync: bool,
+    /// Can the window be resized by the user
+    pub resizable: bool,
+    /// The minimum dimensions the window can be resized to
+    pub min_dimensions: Option<(u32, u32)>,
+    /// The maximum dimensions the window can be resized to
+    pub max_dimensions: Option<(u32, u32)>,
+    /// Whether the area the TextBuffers are drawn in is rounded down so that every character is a whole amount of pixels.
+    ///
+    /// The leftover space is left empty around the TextBuffer. Only applies when `text_buffer_aspect_ratio` is true.
+    pub snap_to_cells: bool,
+    /// The amplitude and frequency multipliers of the shaking effect (see [`TextStyle::shakiness`](text_buffer/struct.TextStyle.html#structfield.shakiness)).
+    pub shake_params: (f32, f32),
+    /// The minimum OpenGL version (major, minor) the `Terminal` requires from the graphics driver.
+    pub min_gl: (u32, u32),
+    /// Whether OpenGL debug messages are printed to stderr, if the driver supports them.
+    pub gl_debug: bool,
 }
 
 impl Default for TerminalBuilder {
@@ -47,11 +78,18 @@ impl Default for TerminalBuilder {
             title: "Hello, Glerminal!".to_owned(),
             dimensions: (1280, 720),
             clear_color: (0.14, 0.19, 0.28, 1.0),
-            font: Font::load_raw(&FontFormat::SFL, SCP_FONT, SCP_PNG),
+            font: default_font(),
             visibility: true,
             headless: false,
             text_buffer_aspect_ratio: true,
             vsync: true,
+            resizable: true,
+            min_dimensions: None,
+            max_dimensions: None,
+            snap_to_cells: false,
+            shake_params: (1.0, 1.0),
+            min_gl: (3, 3),
+            gl_debug: false,
         }
     }
 }
@@ -82,6 +120,8 @@ impl TerminalBuilder {
     }
 
     /// Changes the font that the terminal uses.
+    ///
+    /// Required if the `bundled_font` feature is disabled.
     pub fn with_font(mut self, font: Font) -> TerminalBuilder {
         self.font = font;
         self
@@ -117,8 +157,76 @@ impl TerminalBuilder {
         self
     }
 
+    /// Changes whether the window can be resized by the user. Enabled by default.
+    pub fn with_resizable(mut self, resizable: bool) -> TerminalBuilder {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Sets the minimum dimensions the window can be resized to.
+    pub fn with_min_dimensions(mut self, dimensions: (u32, u32)) -> TerminalBuilder {
+        self.min_dimensions = Some(dimensions);
+        self
+    }
+
+    /// Sets the maximum dimensions the window can be resized to.
+    pub fn with_max_dimensions(mut self, dimensions: (u32, u32)) -> TerminalBuilder {
+        self.max_dimensions = Some(dimensions);
+        self
+    }
+
+    /// Changes whether the area the TextBuffers are drawn in is rounded down so that every character is a whole amount of pixels.
+    /// This keeps the characters from being resampled blurry. Disabled by default.
+    ///
+    /// The leftover space is left empty around the TextBuffer. Only applies when `text_buffer_aspect_ratio` is true.
+    pub fn with_snap_to_cells(mut self, snap_to_cells: bool) -> TerminalBuilder {
+        self.snap_to_cells = snap_to_cells;
+        self
+    }
+
+    /// Sets the amplitude and frequency multipliers of the shaking effect. Default is `(1.0, 1.0)`.
+    ///
+    /// The `shakiness` of each character's `TextStyle` then scales the shaking within these parameters.
+    pub fn with_shake_params(mut self, amplitude: f32, frequency: f32) -> TerminalBuilder {
+        self.shake_params = (amplitude, frequency);
+        self
+    }
+
+    /// Sets the minimum OpenGL version (major, minor) required from the graphics driver. Default is `(3, 3)`.
+    ///
+    /// Building the `Terminal` fails if the driver only supports an older version.
+    /// Lowering this allows trying to run on older drivers, but the shaders require OpenGL 3.3 so they might not work.
+    pub fn with_min_gl(mut self, major: u32, minor: u32) -> TerminalBuilder {
+        self.min_gl = (major, minor);
+        self
+    }
+
+    /// Sets whether OpenGL debug messages (from `KHR_debug`) are printed to stderr. Disabled by default.
+    ///
+    /// Useful for diagnosing rendering issues. If the driver does not support debug output, this does nothing.
+    /// Some drivers only report messages in debug-mode, where a debug context is created.
+    pub fn with_gl_debug(mut self, gl_debug: bool) -> TerminalBuilder {
+        self.gl_debug = gl_debug;
+        self
+    }
+
     /// Builds the actual terminal and opens the window
+    ///
+    /// Panics if no font was given with `with_font` and the `bundled_font` feature is disabled,
+    /// if the window or the OpenGL context could not be created,
+    /// or if the OpenGL version is lower than required (see [`with_min_gl`](#method.with_min_gl)).
+    /// See [`try_build`](#method.try_build) for a non-panicking version.
     pub fn build(self) -> Terminal {
+        match Terminal::new(self) {
+            Ok(terminal) => terminal,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Builds the actual terminal and opens the window, like [`build`](#method.build), but returns an error instead of panicking.
+    ///
+    /// Useful for applications that must fail gracefully, for example when no GPU is available.
+    pub fn try_build(self) -> Result<Terminal, String> {
         Terminal::new(self)
     }
 }
@@ -129,6 +237,7 @@ impl TerminalBuilder {
 /// which can then draw it, close the window, reset the title of the window or handle events.
 ///
 /// **Note** when building with debug-mode, you are able to press `F3` to toggle between debug and non-debug. see ([`set_debug`](#method.set_debug)) for more information.
+/// The key can be changed or disabled with [`set_debug_key`](#method.set_debug_key), which also enables it in release-mode.
 ///
 /// The terminal can also be created with `Default::default` (see [Default creation example](#default-creation-example))
 ///
@@ -181,6 +290,7 @@ pub struct Terminal {
     background_program: Program,
     debug_program: Program,
     debug: Cell<bool>,
+    debug_key: Cell<Option<VirtualKeyCode>>,
     running: Cell<bool>,
     pub(crate) headless: bool,
     since_start: SystemTime,
@@ -188,66 +298,142 @@ pub struct Terminal {
 
     timer: RefCell<Timer>,
     text_buffer_aspect_ratio: bool,
+    pub(crate) grid_overlay: RefCell<Option<TextBuffer>>,
+    title: String,
+    shake_params: Cell<(f32, f32)>,
+    gl_debug: bool,
+    snap_to_cells: bool,
+    pub(crate) clear_color: Color,
+    needs_redraw: Cell<bool>,
+    #[cfg(test)]
+    pub(crate) title_updates: u32,
 }
 
 impl Terminal {
-    fn new(builder: TerminalBuilder) -> Terminal {
+    fn new(builder: TerminalBuilder) -> Result<Terminal, String> {
+        let title = builder.title.clone();
+        if builder.font.characters.is_empty() {
+            return Err("No font was given for the Terminal: either enable the `bundled_font` feature or give a font with `TerminalBuilder::with_font`".to_owned());
+        }
+        let font = builder.font;
+        // Without the aspect ratio the TextBuffers fill the whole window, so there is nothing to snap
+        let snap_to_cells = builder.snap_to_cells && builder.text_buffer_aspect_ratio;
         let (display, program, background_program, debug_program) = if builder.headless {
+            // Headless terminals have no GL context to check the version of, except when tests give one
+            if renderer::has_version_override() {
+                renderer::check_gl_version(&renderer::get_version()?, builder.min_gl)?;
+            }
             (None, Program::empty(), Program::empty(), Program::empty())
         } else {
+            let display = Display::try_new(
+                builder.title,
+                builder.dimensions,
+                builder.clear_color,
+                builder.visibility,
+                builder.text_buffer_aspect_ratio,
+                builder.vsync,
+                snap_to_cells,
+            )?;
+            renderer::check_gl_version(&renderer::get_version()?, builder.min_gl)?;
             (
-                Some(Display::new(
-                    builder.title,
-                    builder.dimensions,
-                    builder.clear_color,
-                    builder.visibility,
-                    builder.text_buffer_aspect_ratio,
-                    builder.vsync,
-                )),
+                Some(display),
                 renderer::create_program(renderer::VERT_SHADER, renderer::FRAG_SHADER),
                 renderer::create_program(renderer::VERT_SHADER, renderer::BG_FRAG_SHADER),
                 renderer::create_program(renderer::VERT_SHADER, renderer::DEBUG_FRAG_SHADER),
             )
         };
-        Terminal {
+        let gl_debug = display.is_some() && builder.gl_debug && renderer::enable_debug_output();
+        if let Some(ref display) = display {
+            display.set_resizable(builder.resizable);
+            display.set_min_size(builder.min_dimensions);
+            display.set_max_size(builder.max_dimensions);
+        }
+        Ok(Terminal {
             display,
             program,
             background_program,
             debug_program,
             debug: Cell::new(false),
+            debug_key: Cell::new(if cfg!(debug_assertions) {
+                Some(VirtualKeyCode::F3)
+            } else {
+                None
+            }),
             running: Cell::new(true),
             headless: builder.headless,
             since_start: SystemTime::now(),
-            font: builder.font,
+            font,
             timer: RefCell::new(Timer::new()),
             text_buffer_aspect_ratio: builder.text_buffer_aspect_ratio,
-        }
+            grid_overlay: RefCell::new(None),
+            title,
+            shake_params: Cell::new(builder.shake_params),
+            gl_debug,
+            snap_to_cells,
+            clear_color: [
+                builder.clear_color.0,
+                builder.clear_color.1,
+                builder.clear_color.2,
+                builder.clear_color.3,
+            ],
+            needs_redraw: Cell::new(true),
+            #[cfg(test)]
+            title_updates: 0,
+        })
     }
 
     /// Sets debug mode (changes characters and backgrounds into wireframe)
     pub fn set_debug(&self, debug: bool) {
         if !self.headless {
             renderer::set_debug(debug);
-            self.debug.set(debug);
         }
+        self.debug.set(debug);
+    }
+
+    /// Sets the amplitude and frequency multipliers of the shaking effect, see [`TerminalBuilder::with_shake_params`](struct.TerminalBuilder.html#method.with_shake_params).
+    pub fn set_shake_params(&self, amplitude: f32, frequency: f32) {
+        self.shake_params.set((amplitude, frequency));
+    }
+
+    /// Returns the amplitude and frequency multipliers of the shaking effect.
+    pub fn get_shake_params(&self) -> (f32, f32) {
+        self.shake_params.get()
+    }
+
+    /// Returns whether OpenGL debug messages are being printed (see [`TerminalBuilder::with_gl_debug`](struct.TerminalBuilder.html#method.with_gl_debug)).
+    ///
+    /// Always false when headless or when the driver does not support debug output.
+    pub fn is_gl_debug(&self) -> bool {
+        self.gl_debug
+    }
+
+    /// Returns whether the area the TextBuffers are drawn in is snapped to whole pixels per character
+    /// (see [`TerminalBuilder::with_snap_to_cells`](struct.TerminalBuilder.html#method.with_snap_to_cells)).
+    ///
+    /// Always false when `text_buffer_aspect_ratio` is false.
+    pub fn is_snapping_to_cells(&self) -> bool {
+        self.snap_to_cells
+    }
+
+    /// Returns whether debug mode is on.
+    pub fn is_debug(&self) -> bool {
+        self.debug.get()
+    }
+
+    /// Sets the key that toggles debug mode (see [`set_debug`](#method.set_debug)), or None to disable toggling with a key.
+    ///
+    /// Default is `F3` in debug-mode and None in release-mode.
+    pub fn set_debug_key(&self, key: Option<VirtualKeyCode>) {
+        self.debug_key.set(key);
     }
 
     /// Refreshes the screen and returns whether the while-loop should continue (is the program running)
-    #[cfg(debug_assertions)]
+    ///
+    /// This is the same as calling [`present`](#method.present) and [`process_pending_events`](#method.process_pending_events),
+    /// and then clearing the screen.
     pub fn refresh(&self) -> bool {
-        let mut timer = self.timer.borrow_mut();
-        timer.update();
-        drop(timer);
-
-        let running = if let Some(ref display) = self.display {
-            let events = self.get_current_events();
-            if events.keyboard.was_just_pressed(VirtualKeyCode::F3) {
-                self.set_debug(!self.debug.get());
-            }
-            display.refresh() && self.running.get()
-        } else {
-            self.running.get()
-        };
+        self.present();
+        let running = self.process_pending_events();
 
         if running && !self.headless {
             renderer::clear();
@@ -255,56 +441,234 @@ impl Terminal {
         running
     }
 
-    /// Refreshes the screen and returns whether the while-loop should continue (is the program running)
-    #[cfg(not(debug_assertions))]
-    pub fn refresh(&self) -> bool {
-        let mut timer = self.timer.borrow_mut();
-        timer.update();
-        drop(timer);
+    /// Polls the pending window events and updates the `Events` and the delta-time, without presenting or clearing the screen.
+    /// Returns whether the program is still running.
+    ///
+    /// Useful together with [`present`](#method.present) when the main loop is driven by something else than [`refresh`](#method.refresh).
+    pub fn process_pending_events(&self) -> bool {
+        self.timer.borrow_mut().update();
 
-        let running = if let Some(ref display) = self.display {
-            display.refresh() && self.running.get()
-        } else {
-            self.running.get()
-        };
+        if let Some(ref display) = self.display {
+            if !display.process_events() {
+                self.running.set(false);
+            }
+            let events = self.get_current_events();
+            if !events.get_terminal_events().is_empty() {
+                self.needs_redraw.set(true);
+            }
+            self.handle_debug_key(&events);
+        }
+        self.running.get()
+    }
 
-        if running && !self.headless {
-            renderer::clear();
+    /// Presents everything drawn since the last `present` or `refresh` by swapping the window's buffers.
+    ///
+    /// Useful together with [`process_pending_events`](#method.process_pending_events) when the main loop is driven by something else than [`refresh`](#method.refresh).
+    pub fn present(&self) {
+        if let Some(ref display) = self.display {
+            display.present();
+        }
+    }
+
+    /// Runs the main loop, calling `frame` with the terminal and the delta-time (in seconds) every frame, until the program stops running.
+    ///
+    /// This is the same as calling `frame` in a `while terminal.refresh() { .. }` loop.
+    ///
+    /// ```no_run
+    /// use glerminal::{TerminalBuilder, TextBuffer};
+    ///
+    /// let terminal = TerminalBuilder::new().build();
+    /// let mut text_buffer = TextBuffer::create(&terminal, (80, 24)).unwrap();
+    ///
+    /// terminal.run(|terminal, _delta| {
+    ///     terminal.flush(&mut text_buffer);
+    ///     terminal.draw(&text_buffer);
+    /// });
+    /// ```
+    pub fn run<F: FnMut(&Terminal, f32)>(&self, mut frame: F) {
+        while self.refresh() {
+            frame(self, self.delta_time());
         }
-        running
     }
 
     /// Flushes `TextBuffer`, taking it's character-grid and making it show for the next draw.
     ///
     /// This is quite a heavy function and it's calling should be avoided when unnecessary.
     pub fn flush(&self, text_buffer: &mut TextBuffer) {
-        text_buffer.swap_buffers(&self.font);
+        if text_buffer.swap_buffers(&self.font) {
+            self.needs_redraw.set(true);
+        }
+    }
+
+    /// Returns whether something has changed since the last draw, so that the screen should be drawn again.
+    ///
+    /// A redraw is needed when a flushed `TextBuffer` had changed, when any events (see [`poll_events`](#method.poll_events)) were received,
+    /// or when [`request_redraw`](#method.request_redraw) was called. Drawing anything resets this.
+    ///
+    /// Useful for low-power applications, that only draw and [`present`](#method.present) when needed,
+    /// and otherwise only call [`process_pending_events`](#method.process_pending_events) (instead of `refresh`, which clears the screen).
+    /// Note that shaking text is not considered a change.
+    pub fn needs_redraw(&self) -> bool {
+        self.needs_redraw.get()
+    }
+
+    /// Forces [`needs_redraw`](#method.needs_redraw) to return true until the next draw.
+    pub fn request_redraw(&self) {
+        self.needs_redraw.set(true);
     }
 
     /// Draws a `TextBuffer`. This should be called every frame for each text buffer.
     pub fn draw(&self, text_buffer: &TextBuffer) {
-        if let (&Some(ref display), &Some(ref mesh), &Some(ref background_mesh)) = (
-            &self.display,
-            &text_buffer.mesh,
-            &text_buffer.background_mesh,
-        ) {
+        self.needs_redraw.set(false);
+        if let Some(ref display) = self.display {
+            // The display data is registered even without the aspect ratio, so the cursor knows the grid of the TextBuffer.
+            // The cursor then uses the display-wide overflows instead of the ones in the data.
+            let display_data = display.get_display_data(&text_buffer);
             let proj_matrix = if self.text_buffer_aspect_ratio {
-                display.get_display_data(&text_buffer).proj_matrix
+                display_data.proj_matrix
             } else {
                 display.proj_matrix.get()
             };
+            self.draw_with_proj_matrix(text_buffer, proj_matrix);
+        }
+    }
 
-            let duration = SystemTime::now().duration_since(self.since_start).unwrap();
+    /// Draws the `TextBuffer` like [`draw`](#method.draw), but with the given `BlendMode`.
+    ///
+    /// The blend mode is restored to `BlendMode::Alpha` afterwards.
+    pub fn draw_with_blend(&self, text_buffer: &TextBuffer, blend_mode: BlendMode) {
+        if self.display.is_some() {
+            renderer::set_blend_mode(blend_mode);
+            self.draw(text_buffer);
+            renderer::set_blend_mode(BlendMode::Alpha);
+        }
+    }
 
-            let time = duration.as_secs() as f32 + duration.subsec_nanos() as f32 / 1_000_000_000.0;
+    /// Draws only the `src` characters (x, y, width, height) of the `TextBuffer` into the `dst` rectangle (x, y, width, height) of the window.
+    ///
+    /// `dst` is relative to the window size, (0.0, 0.0) being the top left corner and (1.0, 1.0) the bottom right corner.
+    /// The characters are stretched to fill `dst`, and nothing is drawn outside of it.
+    pub fn draw_region(
+        &self,
+        text_buffer: &TextBuffer,
+        src: (u32, u32, u32, u32),
+        dst: (f32, f32, f32, f32),
+    ) {
+        self.needs_redraw.set(false);
+        if src.2 == 0 || src.3 == 0 {
+            return;
+        }
+        if let Some(ref display) = self.display {
+            let proj_matrix =
+                renderer::create_region_proj_matrix(text_buffer.get_dimensions(), src, dst);
+            renderer::set_scissor(Some(renderer::calc_scissor(display.get_dimensions(), dst)));
+            self.draw_with_proj_matrix(text_buffer, proj_matrix);
+            renderer::set_scissor(None);
+        }
+    }
+
+    /// Draws the `TextBuffer`, but only the characters inside the given rectangle (x, y, width, height).
+    ///
+    /// Useful for drawing only a part of a `TextBuffer`, ie. a popup on top of another `TextBuffer`.
+    pub fn draw_clipped(&self, text_buffer: &TextBuffer, rect_cells: (u32, u32, u32, u32)) {
+        if let Some(ref display) = self.display {
+            let (overflows, relative_dimensions) =
+                self.get_overflows_dimensions(display, text_buffer);
+            let rect = renderer::calc_cells_rect(
+                text_buffer.get_dimensions(),
+                rect_cells,
+                overflows,
+                relative_dimensions,
+            );
+            renderer::set_scissor(Some(renderer::calc_scissor(display.get_dimensions(), rect)));
+            self.draw(text_buffer);
+            renderer::set_scissor(None);
+        }
+    }
+
+    fn get_overflows_dimensions(
+        &self,
+        display: &Display,
+        text_buffer: &TextBuffer,
+    ) -> ((f32, f32), (f32, f32)) {
+        if self.text_buffer_aspect_ratio {
+            let data = display.get_display_data(text_buffer);
+            (data.overflows, data.relative_dimensions)
+        } else {
+            display.get_overflows_dimensions()
+        }
+    }
+
+    fn draw_with_proj_matrix(&self, text_buffer: &TextBuffer, proj_matrix: Matrix4) {
+        if let (&Some(ref mesh), &Some(ref background_mesh)) =
+            (&text_buffer.mesh, &text_buffer.background_mesh)
+        {
+            let time = text_buffer.calc_time(self.elapsed());
+
+            let shake_params = self.shake_params.get();
 
             renderer::draw(
                 self.get_background_program(),
                 proj_matrix,
                 time,
+                shake_params,
                 background_mesh,
             );
-            renderer::draw(self.get_program(), proj_matrix, time, mesh);
+            renderer::draw(self.get_program(), proj_matrix, time, shake_params, mesh);
+        }
+    }
+
+    /// Draws a faint overlay on top of the given `TextBuffer`, showing the boundaries of the characters in a checkerboard pattern
+    /// and the last digit of the column and row indices on the first row and column. Useful for debugging layouts.
+    ///
+    /// Should be called after drawing the `TextBuffer`, and only when the overlay is wanted.
+    pub fn draw_grid_overlay(&self, text_buffer: &TextBuffer) {
+        let mut grid_overlay = self.grid_overlay.borrow_mut();
+        let outdated = match *grid_overlay {
+            Some(ref overlay) => overlay.get_dimensions() != text_buffer.get_dimensions(),
+            None => true,
+        };
+        if outdated {
+            *grid_overlay = TextBuffer::create(self, text_buffer.get_dimensions())
+                .ok()
+                .map(|mut overlay| {
+                    Terminal::write_grid_overlay(&mut overlay);
+                    overlay.swap_buffers(&self.font);
+                    overlay
+                });
+        }
+        if let Some(ref overlay) = *grid_overlay {
+            self.draw(overlay);
+        }
+    }
+
+    fn write_grid_overlay(overlay: &mut TextBuffer) {
+        let (width, height) = overlay.get_dimensions();
+        for y in 0..height {
+            for x in 0..width {
+                let alpha = if x % 10 == 0 || y % 10 == 0 {
+                    0.12
+                } else if (x + y) % 2 == 0 {
+                    0.06
+                } else {
+                    0.0
+                };
+                overlay.cursor.style.bg_color = [1.0, 1.0, 1.0, alpha];
+                overlay.cursor.style.fg_color = [1.0, 1.0, 1.0, 0.4];
+                overlay.cursor.move_to(x, y);
+
+                let index = if y == 0 && x > 0 {
+                    Some(x)
+                } else if x == 0 && y > 0 {
+                    Some(y)
+                } else {
+                    None
+                };
+                match index {
+                    Some(index) => overlay.put_char(std::char::from_digit(index % 10, 10).unwrap()),
+                    None => overlay.put_char(' '),
+                }
+            }
         }
     }
 
@@ -317,6 +681,27 @@ impl Terminal {
         }
     }
 
+    /// Returns the discrete events (key presses, mouse movement, received characters, etc.) that happened since the last `refresh`, in the order they happened.
+    ///
+    /// This is an alternative to inspecting the snapshot returned by [`get_current_events`](#method.get_current_events), and both can be used at the same time.
+    ///
+    /// ```no_run
+    /// use glerminal::{TerminalBuilder, TerminalEvent};
+    ///
+    /// let terminal = TerminalBuilder::new().build();
+    ///
+    /// while terminal.refresh() {
+    ///     for event in terminal.poll_events() {
+    ///         if let TerminalEvent::Char(character) = event {
+    ///             println!("Typed {}", character);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn poll_events(&self) -> Vec<TerminalEvent> {
+        self.get_current_events().get_terminal_events()
+    }
+
     /// Closes the Terminal
     pub fn close(&self) {
         self.running.set(false);
@@ -324,10 +709,20 @@ impl Terminal {
 
     /// Sets the title for the window.
     ///
-    /// **Warning:** This is a nuclear hazard (takes up a lot of performance), it might melt down your computer if called every frame (or so).
+    /// **Warning:** Changing the title is a nuclear hazard (takes up a lot of performance), it might melt down your computer if done every frame (or so).
+    /// Setting the same title as the current one does nothing however, so it is safe to call this every frame with an unchanging title.
     pub fn set_title<T: Into<String>>(&mut self, title: T) {
+        let title = title.into();
+        if title == self.title {
+            return;
+        }
         if let Some(ref mut display) = self.display {
-            display.set_title(&title.into());
+            display.set_title(&title);
+        }
+        self.title = title;
+        #[cfg(test)]
+        {
+            self.title_updates += 1;
         }
     }
 
@@ -338,11 +733,141 @@ impl Terminal {
         }
     }
 
+    /// Tries to enable or disable vsync, returns whether vsync is now in the requested state.
+    ///
+    /// **Note:** Changing vsync after the window has been opened is not supported by the windowing backend,
+    /// so this only returns true if vsync already was in the requested state (see [`TerminalBuilder::with_vsync`](struct.TerminalBuilder.html#method.with_vsync)).
+    /// Headless terminals accept any value.
+    pub fn set_vsync(&mut self, vsync: bool) -> bool {
+        match self.display {
+            Some(ref display) => display.set_vsync(vsync),
+            None => true,
+        }
+    }
+
+    /// Sets whether the window can be resized by the user.
+    pub fn set_resizable(&self, resizable: bool) {
+        if let Some(ref display) = self.display {
+            display.set_resizable(resizable);
+        }
+    }
+
+    /// Sets the minimum dimensions the window can be resized to, or None for no limit.
+    pub fn set_min_size(&self, dimensions: Option<(u32, u32)>) {
+        if let Some(ref display) = self.display {
+            display.set_min_size(dimensions);
+        }
+    }
+
+    /// Sets the maximum dimensions the window can be resized to, or None for no limit.
+    pub fn set_max_size(&self, dimensions: Option<(u32, u32)>) {
+        if let Some(ref display) = self.display {
+            display.set_max_size(dimensions);
+        }
+    }
+
+    /// Shows or hides the OS cursor while it's over the window.
+    pub fn set_cursor_visible(&self, visible: bool) -> Result<(), String> {
+        if let Some(ref display) = self.display {
+            display.set_cursor_visible(visible)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Confines the OS cursor to the window and hides it. Cursor movement is still reported through [`Events`](struct.Events.html).
+    pub fn set_cursor_grab(&self, grab: bool) -> Result<(), String> {
+        if let Some(ref display) = self.display {
+            display.set_cursor_grab(grab)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Moves the OS cursor to the center of the given cell (x, y) of the `TextBuffer`, the inverse of [`Cursor::get_location`](struct.Cursor.html#method.get_location).
+    ///
+    /// Does nothing if the Terminal is headless.
+    pub fn set_cursor_position(
+        &self,
+        cell: (u32, u32),
+        text_buffer: &TextBuffer,
+    ) -> Result<(), String> {
+        if let Some(ref display) = self.display {
+            let (overflows, relative_dimensions) =
+                self.get_overflows_dimensions(display, text_buffer);
+            let rect = renderer::calc_cells_rect(
+                text_buffer.get_dimensions(),
+                (cell.0, cell.1, 1, 1),
+                overflows,
+                relative_dimensions,
+            );
+            display.set_cursor_position(renderer::calc_rect_center(display.get_dimensions(), rect))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the font the terminal uses, for example for measuring text or querying glyphs.
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    /// Returns the size of a single character cell of the font in pixels (width, height), as the font was loaded.
+    pub fn cell_pixel_size(&self) -> (u32, u32) {
+        self.grid_pixel_size(1, 1)
+    }
+
+    /// Returns the size in pixels (width, height) a grid of characters would take with the font, as the font was loaded.
+    ///
+    /// Useful for sizing the window so that a `TextBuffer` of the given dimensions fits it exactly.
+    pub fn grid_pixel_size(&self, cols: u32, rows: u32) -> (u32, u32) {
+        (
+            (cols as f32 * self.font.average_xadvance) as u32,
+            rows * self.font.line_height,
+        )
+    }
+
     /// Get the delta-time (in seconds).
     pub fn delta_time(&self) -> f32 {
         self.timer.borrow().get_delta_time()
     }
 
+    /// Get the time (in seconds) since the Terminal was created.
+    ///
+    /// This is the same clock that drives the shaking of the text, see also [`TextBuffer::set_time_scale`](struct.TextBuffer.html#method.set_time_scale).
+    pub fn elapsed(&self) -> f32 {
+        let duration = SystemTime::now().duration_since(self.since_start).unwrap();
+        duration.as_secs() as f32 + duration.subsec_nanos() as f32 / 1_000_000_000.0
+    }
+
+    /// Get the average delta-time (in seconds) of the last 30 frames.
+    pub fn smoothed_delta(&self) -> f32 {
+        self.timer.borrow().get_smoothed_delta()
+    }
+
+    /// Get the statistics of the delta-times of the last 240 frames, useful for diagnosing stutter.
+    pub fn frame_stats(&self) -> FrameStats {
+        self.timer.borrow().get_frame_stats()
+    }
+
+    /// Get the average frames per second of the last 30 frames.
+    pub fn average_fps(&self) -> f32 {
+        let delta = self.smoothed_delta();
+        if delta > 0.0 {
+            1.0 / delta
+        } else {
+            0.0
+        }
+    }
+
+    pub(crate) fn handle_debug_key(&self, events: &Events) {
+        if let Some(key) = self.debug_key.get() {
+            if events.keyboard.was_just_pressed(key) {
+                self.set_debug(!self.debug.get());
+            }
+        }
+    }
+
     pub(crate) fn get_program(&self) -> Program {
         if self.headless {
             panic!("Unable to get program from headless terminal");
@@ -373,9 +898,38 @@ impl Terminal {
     }
 }
 
+#[cfg(feature = "bundled_font")]
+fn default_font() -> Font {
+    Font::load_raw(&crate::FontFormat::SFL, SCP_FONT, SCP_PNG)
+}
+
+#[cfg(not(feature = "bundled_font"))]
+fn default_font() -> Font {
+    Font::empty()
+}
+
+/// Statistics of the delta-times (in seconds) of the last frames, see [`Terminal::frame_stats`](struct.Terminal.html#method.frame_stats).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FrameStats {
+    /// The shortest delta-time
+    pub min: f32,
+    /// The longest delta-time
+    pub max: f32,
+    /// The average delta-time
+    pub avg: f32,
+    /// The 99th percentile of the delta-times, meaning 99% of the frames took at most this long
+    pub p99: f32,
+}
+
+const SMOOTHING_FRAMES: usize = 30;
+const STATS_FRAMES: usize = 240;
+
 pub(crate) struct Timer {
     last_check: SystemTime,
     delta_time: f32,
+    deltas: [f32; STATS_FRAMES],
+    delta_idx: usize,
+    delta_count: usize,
 }
 
 impl Timer {
@@ -383,6 +937,9 @@ impl Timer {
         Timer {
             last_check: SystemTime::now(),
             delta_time: 0.0,
+            deltas: [0.0; STATS_FRAMES],
+            delta_idx: 0,
+            delta_count: 0,
         }
     }
 
@@ -391,11 +948,51 @@ impl Timer {
         let duration = current_time.duration_since(self.last_check).unwrap();
         self.last_check = current_time;
 
-        self.delta_time =
-            duration.as_secs() as f32 + duration.subsec_nanos() as f32 / 1_000_000_000.0;
+        self.push_delta(
+            duration.as_secs() as f32 + duration.subsec_nanos() as f32 / 1_000_000_000.0,
+        );
+    }
+
+    pub fn push_delta(&mut self, delta: f32) {
+        self.delta_time = delta;
+        self.deltas[self.delta_idx] = delta;
+        self.delta_idx = (self.delta_idx + 1) % STATS_FRAMES;
+        self.delta_count = (self.delta_count + 1).min(STATS_FRAMES);
     }
 
     pub fn get_delta_time(&self) -> f32 {
         self.delta_time
     }
+
+    pub fn get_smoothed_delta(&self) -> f32 {
+        let count = self.delta_count.min(SMOOTHING_FRAMES);
+        if count == 0 {
+            0.0
+        } else {
+            self.last_deltas(count).sum::<f32>() / count as f32
+        }
+    }
+
+    pub fn get_frame_stats(&self) -> FrameStats {
+        if self.delta_count == 0 {
+            return FrameStats::default();
+        }
+        let mut deltas: Vec<f32> = self.last_deltas(self.delta_count).collect();
+        deltas.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        // Nearest-rank percentile
+        let p99_idx = ((deltas.len() as f32 * 0.99).ceil() as usize).max(1) - 1;
+        FrameStats {
+            min: deltas[0],
+            max: deltas[deltas.len() - 1],
+            avg: deltas.iter().sum::<f32>() / deltas.len() as f32,
+            p99: deltas[p99_idx],
+        }
+    }
+
+    /// Iterates the last `count` deltas, newest first
+    fn last_deltas<'a>(&'a self, count: usize) -> impl Iterator<Item = f32> + 'a {
+        (1..=count)
+            .map(move |offset| self.deltas[(self.delta_idx + STATS_FRAMES - offset) % STATS_FRAMES])
+    }
 }
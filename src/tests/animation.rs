@@ -0,0 +1,40 @@
+use crate::animation::{ease, Easing, Tween};
+
+#[test]
+fn easings() {
+    let easings = [
+        (Easing::Linear, 0.5),
+        (Easing::EaseIn, 0.25),
+        (Easing::EaseOut, 0.75),
+        (Easing::EaseInOut, 0.5),
+    ];
+    for (easing, half) in easings.iter() {
+        assert_eq!(ease(*easing, 0.0), 0.0);
+        assert_eq!(ease(*easing, 0.5), *half);
+        assert_eq!(ease(*easing, 1.0), 1.0);
+        assert_eq!(ease(*easing, 2.0), 1.0);
+    }
+}
+
+#[test]
+fn tween_reaches_target() {
+    let mut tween =
+        Tween::new([0.0, 0.0, 0.0, 1.0], [1.0, 0.5, 0.0, 1.0], 1.0).with_easing(Easing::EaseOut);
+
+    tween.update(0.5);
+    assert!(!tween.is_finished());
+    assert_eq!(tween.get_value(), [0.75, 0.375, 0.0, 1.0]);
+
+    tween.update(0.75);
+    assert!(tween.is_finished());
+    assert_eq!(tween.get_value(), [1.0, 0.5, 0.0, 1.0]);
+}
+
+#[test]
+fn tween_position() {
+    let mut tween = Tween::new((0, 10), (10, 0), 2.0);
+    tween.update(1.0);
+    assert_eq!(tween.get_value(), (5, 5));
+    tween.update(1.0);
+    assert_eq!(tween.get_value(), (10, 0));
+}
@@ -1,6 +1,8 @@
-use super::test_setup_open_terminal;
+use super::{test_setup_open_terminal, test_setup_text_buffer};
+use crate::display::TextBufferDisplayData;
 use crate::events::Events;
-use glutin::VirtualKeyCode;
+use glutin::{MouseButton, VirtualKeyCode};
+use std::collections::HashMap;
 
 #[test]
 fn was_just_pressed() {
@@ -51,3 +53,274 @@ fn was_just_pressed_with_terminal() {
         assert_eq!(events.keyboard.was_just_pressed(button), false);
     }
 }
+
+#[test]
+fn cursor_delta() {
+    let mut events = Events::new(true);
+    events.cursor.update_location((0.25, 0.5));
+    assert_eq!(events.cursor.get_delta(), (0.0, 0.0));
+    events.cursor.update_location((0.5, 0.25));
+    assert_eq!(events.cursor.get_delta(), (0.25, -0.25));
+    events.clear_just_lists();
+    assert_eq!(events.cursor.get_delta(), (0.0, 0.0));
+}
+
+#[test]
+fn cursor_position_history() {
+    let mut events = Events::new(true);
+    assert!(events.cursor.position_history().is_empty());
+
+    events.cursor.update_location((0.1, 0.5));
+    events.cursor.update_location((0.2, 0.5));
+    events.clear_just_lists();
+    events.cursor.update_location((0.3, 0.4));
+    assert_eq!(
+        events.cursor.position_history(),
+        &[(0.1, 0.5), (0.2, 0.5), (0.3, 0.4)]
+    );
+
+    // Only the latest positions are kept
+    for idx in 0..20 {
+        events.cursor.update_location((idx as f32 / 20.0, 0.0));
+    }
+    let history = events.cursor.position_history();
+    assert_eq!(history.len(), 16);
+    assert_eq!(history[0], (4.0 / 20.0, 0.0));
+    assert_eq!(history[15], (19.0 / 20.0, 0.0));
+
+    events.cursor.cursor_left();
+    assert!(events.cursor.position_history().is_empty());
+}
+
+#[test]
+fn any_just_pressed() {
+    let mut events = Events::new(true);
+    assert!(!events.any_just_pressed());
+    events.keyboard.update_button_press(VirtualKeyCode::Space, true);
+    assert!(events.any_just_pressed());
+    events.clear_just_lists();
+    assert!(!events.any_just_pressed());
+    events.mouse.update_button_press(MouseButton::Left, true);
+    assert!(events.any_just_pressed());
+}
+
+#[test]
+fn typed_text() {
+    let mut events = Events::new(true);
+    assert_eq!(events.typed_text(), "");
+    for character in "héllo".chars() {
+        events.chars.add_char(character);
+    }
+    assert_eq!(events.typed_text(), "héllo");
+    events.clear_just_lists();
+    assert_eq!(events.typed_text(), "");
+}
+
+#[test]
+fn keycode_to_char() {
+    use crate::events::keycode_to_char;
+
+    assert_eq!(keycode_to_char(VirtualKeyCode::A, false), Some('a'));
+    assert_eq!(keycode_to_char(VirtualKeyCode::A, true), Some('A'));
+    assert_eq!(keycode_to_char(VirtualKeyCode::Key1, false), Some('1'));
+    assert_eq!(keycode_to_char(VirtualKeyCode::Key1, true), Some('!'));
+    assert_eq!(keycode_to_char(VirtualKeyCode::Slash, true), Some('?'));
+    assert_eq!(keycode_to_char(VirtualKeyCode::Escape, false), None);
+    assert_eq!(keycode_to_char(VirtualKeyCode::F1, true), None);
+}
+
+#[test]
+fn chord_just_pressed() {
+    let mut events = Events::new(true);
+    let chord = [VirtualKeyCode::LControl, VirtualKeyCode::S];
+    assert!(!events.keyboard.chord_just_pressed(&chord));
+    assert!(!events.keyboard.chord_just_pressed(&[]));
+
+    // Only the modifier held
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::LControl, true);
+    assert!(!events.keyboard.chord_just_pressed(&chord));
+    events.clear_just_lists();
+
+    events.keyboard.update_button_press(VirtualKeyCode::S, true);
+    assert!(events.keyboard.chord_just_pressed(&chord));
+    assert_eq!(
+        events.keyboard.just_pressed_iter().collect::<Vec<_>>(),
+        vec![VirtualKeyCode::S]
+    );
+
+    // Still held, but not just pressed
+    events.clear_just_lists();
+    assert!(!events.keyboard.chord_just_pressed(&chord));
+
+    // The last key is pressed without the modifier
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::S, false);
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::LControl, false);
+    events.clear_just_lists();
+    events.keyboard.update_button_press(VirtualKeyCode::S, true);
+    assert!(!events.keyboard.chord_just_pressed(&chord));
+}
+
+#[test]
+fn keycode_name() {
+    use crate::events::keycode_name;
+
+    assert_eq!(keycode_name(VirtualKeyCode::A), "A");
+    assert_eq!(keycode_name(VirtualKeyCode::Key1), "1");
+    assert_eq!(keycode_name(VirtualKeyCode::F12), "F12");
+    assert_eq!(keycode_name(VirtualKeyCode::Return), "Enter");
+    assert_eq!(keycode_name(VirtualKeyCode::Up), "Up Arrow");
+    assert_eq!(keycode_name(VirtualKeyCode::RControl), "Ctrl");
+    assert_eq!(keycode_name(VirtualKeyCode::PageDown), "Page Down");
+    assert_eq!(keycode_name(VirtualKeyCode::Numpad5), "Numpad 5");
+    assert_eq!(keycode_name(VirtualKeyCode::Backslash), "\\");
+    assert_eq!(keycode_name(VirtualKeyCode::WebHome), "Unknown");
+}
+
+#[test]
+fn terminal_events() {
+    use crate::events::TerminalEvent;
+
+    let mut events = Events::new(true);
+    events.update_key(VirtualKeyCode::A, true);
+    // Repeated presses are not registered again
+    events.update_key(VirtualKeyCode::A, true);
+    events.update_mouse_button(MouseButton::Left, true);
+    events.update_cursor_location((0.5, 0.25));
+    events.add_char('a');
+    events.update_key(VirtualKeyCode::A, false);
+    events.update_resized((640, 480));
+    events.push_event(TerminalEvent::Closed);
+
+    assert_eq!(
+        events.get_terminal_events(),
+        vec![
+            TerminalEvent::KeyPressed(VirtualKeyCode::A),
+            TerminalEvent::MousePressed(MouseButton::Left),
+            TerminalEvent::MouseMoved((0.5, 0.25)),
+            TerminalEvent::Char('a'),
+            TerminalEvent::KeyReleased(VirtualKeyCode::A),
+            TerminalEvent::Resized((640, 480)),
+            TerminalEvent::Closed,
+        ]
+    );
+    assert!(events.keyboard.was_just_pressed(VirtualKeyCode::A));
+    assert_eq!(events.chars.get_chars(), vec!['a']);
+
+    events.clear_just_lists();
+    assert!(events.get_terminal_events().is_empty());
+}
+
+#[test]
+fn poll_events_with_terminal() {
+    use crate::events::TerminalEvent;
+
+    let button = VirtualKeyCode::A;
+    let mut terminal = test_setup_open_terminal();
+    if !terminal.headless {
+        terminal.update_virtual_keycode(button, true);
+        assert_eq!(
+            terminal.poll_events(),
+            vec![TerminalEvent::KeyPressed(button)]
+        );
+    } else {
+        assert!(terminal.poll_events().is_empty());
+    }
+}
+
+#[test]
+fn resized() {
+    let mut events = Events::new(true);
+    assert_eq!(events.resized(), None);
+    events.update_resized((800, 600));
+    assert_eq!(events.resized(), Some((800, 600)));
+    events.clear_just_lists();
+    assert_eq!(events.resized(), None);
+}
+
+#[test]
+fn location_for_idx() {
+    let text_buffer = test_setup_text_buffer((8, 4));
+    let mut events = Events::new(true);
+    let mut datas = HashMap::new();
+    datas.insert(
+        text_buffer.get_idx(),
+        TextBufferDisplayData::new(1000.0, 400.0, &text_buffer, false),
+    );
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), datas);
+
+    for location in &[(0.4, 0.1), (0.5, 0.5), (0.6, 0.9), (0.45, 0.7)] {
+        events.cursor.update_location(*location);
+        let (x, y) = events.cursor.get_location(&text_buffer).unwrap();
+        assert_eq!(
+            events.cursor.get_location_for_idx(text_buffer.get_idx()),
+            Some((x as i32, y as i32))
+        );
+    }
+
+    // On the black bar on the left
+    events.cursor.update_location((0.05, 0.5));
+    assert_eq!(events.cursor.get_location(&text_buffer), None);
+    let (x, y) = events
+        .cursor
+        .get_location_for_idx(text_buffer.get_idx())
+        .unwrap();
+    assert!(x < 0);
+    assert_eq!(y, 2);
+    assert_eq!(
+        events
+            .cursor
+            .get_location_for_idx(text_buffer.get_idx() + 1),
+        None
+    );
+}
+
+#[test]
+fn location_for_idx_without_aspect_ratio() {
+    let text_buffer = test_setup_text_buffer((8, 4));
+    let mut events = Events::new(false);
+    let mut datas = HashMap::new();
+    datas.insert(
+        text_buffer.get_idx(),
+        TextBufferDisplayData::new(1000.0, 400.0, &text_buffer, false),
+    );
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), datas);
+
+    // The TextBuffer fills the whole window, so there are no black bars
+    events.cursor.update_location((0.05, 0.5));
+    assert_eq!(events.cursor.get_location(&text_buffer), Some((0, 2)));
+    assert_eq!(
+        events.cursor.get_location_for_idx(text_buffer.get_idx()),
+        Some((0, 2))
+    );
+    events.cursor.update_location((0.95, 0.9));
+    assert_eq!(
+        events.cursor.get_location_for_idx(text_buffer.get_idx()),
+        Some((7, 3))
+    );
+}
+
+#[test]
+fn is_over() {
+    let text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(true);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    events.cursor.update_location((0.35, 0.55));
+
+    assert!(events.cursor.is_over(&text_buffer, (3, 5, 1, 1)));
+    assert!(events.cursor.is_over(&text_buffer, (0, 0, 4, 6)));
+    assert!(!events.cursor.is_over(&text_buffer, (0, 0, 3, 6)));
+    assert!(!events.cursor.is_over(&text_buffer, (4, 5, 2, 2)));
+    assert!(events.cursor.is_over(&text_buffer, (1, 1, u32::MAX, u32::MAX)));
+}
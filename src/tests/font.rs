@@ -1,5 +1,7 @@
 use crate::font::Font;
 use crate::FontFormat;
+use png::Decoder;
+use std::fs::File;
 
 static SCP_SFL: &'static str = include_str!("../../fonts/source_code_pro.sfl");
 static SCP_PNG: &'static [u8] = include_bytes!("../../fonts/source_code_pro.png");
@@ -66,3 +68,37 @@ fn single_character_id() {
     let font = test_load_font();
     assert_eq!(font.get_character('a' as u16).unwrap().id, 97);
 }
+
+#[test]
+fn dump_atlas() {
+    let font = test_load_font();
+    let path = std::env::temp_dir().join("glerminal_dump_atlas_test.png");
+    font.dump_atlas(path.clone()).unwrap();
+
+    let (info, _) = Decoder::new(File::open(&path).unwrap())
+        .read_info()
+        .unwrap();
+    assert_eq!((info.width, info.height), (font.width, font.height));
+    assert!(std::fs::metadata(&path).unwrap().len() > 0);
+    std::fs::remove_file(&path).ok();
+
+    assert!(font.dump_atlas("nonexistent_directory/atlas.png").is_err());
+}
+
+#[test]
+fn debug_character() {
+    let font = test_load_font();
+    let description = font.debug_character('a' as u16).unwrap();
+    assert!(description.starts_with("'a' (id 97)"));
+    assert!(description.contains("size 30x34, offset (3, 29)"));
+    assert_eq!(font.debug_character(0xFFFF), None);
+}
+
+#[test]
+fn metric_accessors() {
+    let font = test_load_font_raw();
+    assert_eq!(font.line_height(), 77);
+    assert_eq!(font.size(), 44);
+    assert_eq!(font.average_xadvance(), font.average_xadvance);
+    assert!(font.average_xadvance() > 0.0);
+}
@@ -0,0 +1,46 @@
+use super::test_setup_text_buffer;
+use crate::menu_systems::{Canvas, InterfaceItem};
+use crate::Events;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn draw_receives_position() {
+    let mut text_buffer = test_setup_text_buffer((10, 10));
+    let positions = Rc::new(RefCell::new(Vec::new()));
+
+    let positions_clone = positions.clone();
+    let mut canvas = Canvas::new(3, 2, move |text_buffer, (x, y)| {
+        text_buffer.cursor.move_to(x, y);
+        text_buffer.put_char('x');
+        positions_clone.borrow_mut().push((x, y));
+    })
+    .with_pos((4, 5));
+
+    assert_eq!(canvas.get_total_width(), 3);
+    assert_eq!(canvas.get_total_height(), 2);
+
+    canvas.draw(&mut text_buffer);
+    assert_eq!(*positions.borrow(), vec![(4, 5)]);
+    assert_eq!(text_buffer.get_character(4, 5).unwrap().get_char(), 'x');
+
+    // Clones share the same closure
+    let mut cloned = canvas.clone();
+    cloned.get_mut_base().set_pos((1, 2));
+    cloned.draw(&mut text_buffer);
+    assert_eq!(*positions.borrow(), vec![(4, 5), (1, 2)]);
+}
+
+#[test]
+fn events_handler() {
+    let events = Events::new(false);
+
+    let mut canvas = Canvas::new(1, 1, |_, _| {});
+    assert!(!canvas.get_base().can_be_focused);
+    assert!(!canvas.handle_events(&events));
+
+    canvas.set_events_handler(|_| true);
+    assert!(canvas.get_base().can_be_focused);
+    assert!(canvas.handle_events(&events));
+}
@@ -41,6 +41,26 @@ fn group() {
     })
 }
 
+#[test]
+fn group_selection_just_changed() {
+    let mut group = CheckboxGroup::new();
+    let mut cb1 = Checkbox::new("");
+    let mut cb2 = Checkbox::new("");
+
+    cb1.set_checked(true);
+    group.update(&mut [&mut cb1, &mut cb2]);
+    assert!(group.selection_just_changed());
+    group.update(&mut [&mut cb1, &mut cb2]);
+    assert!(!group.selection_just_changed());
+
+    cb2.set_checked(true);
+    group.update(&mut [&mut cb1, &mut cb2]);
+    assert!(group.selection_just_changed());
+    assert_eq!(group.get_selection_idx(), Some(1));
+    group.update(&mut [&mut cb1, &mut cb2]);
+    assert!(!group.selection_just_changed());
+}
+
 #[test]
 fn checked() {
     run_multiple_times(50, || {
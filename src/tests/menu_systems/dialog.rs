@@ -2,7 +2,8 @@ use super::{random_text, run_multiple_times, test_setup_text_buffer};
 use crate::menu_systems::{Dialog, InterfaceItem};
 use crate::text_processing::DefaultProcessor;
 use crate::VirtualKeyCode::{Down, Up, L, O};
-use crate::{Events, VirtualKeyCode};
+use crate::{Events, MouseButton, TextBuffer, TextStyle, VirtualKeyCode};
+use std::collections::HashMap;
 
 use rand::{thread_rng, Rng};
 
@@ -154,3 +155,191 @@ fn handle_input() {
         check(0, L, expect);
     });
 }
+
+#[test]
+fn reflow() {
+    let mut text_buffer = test_setup_text_buffer((10, 4));
+
+    let mut dialog = Dialog::new(10, None, None).with_text("aaaa bbbb cccc");
+    dialog.update(0.0, &DefaultProcessor);
+    assert_eq!(dialog.get_total_height(), 2);
+
+    dialog.set_width(5);
+    dialog.reflow();
+    assert_eq!(dialog.get_total_height(), 3);
+
+    dialog.draw(&mut text_buffer);
+    for (idx, row) in ["aaaa", "bbbb", "cccc"].iter().enumerate() {
+        for (char_idx, c) in row.chars().enumerate() {
+            assert_eq!(
+                text_buffer
+                    .get_character(char_idx as u32, idx as u32)
+                    .unwrap()
+                    .get_char(),
+                c
+            );
+        }
+    }
+}
+
+#[test]
+fn line_numbers() {
+    let mut text_buffer = test_setup_text_buffer((8, 3));
+    let text = (0..12)
+        .map(|idx| format!("w{:02}", idx))
+        .collect::<Vec<String>>()
+        .join(" ");
+    let mut dialog = Dialog::new(8, None, None).with_text(text);
+    dialog.update(0.0, &DefaultProcessor);
+    assert_eq!(dialog.get_total_height(), 6);
+
+    // 12 rows need a gutter of 3, leaving room for only one word per row
+    let mut dialog = dialog.with_line_numbers(true);
+    dialog.update(0.0, &DefaultProcessor);
+    assert_eq!(dialog.get_total_height(), 12);
+
+    dialog.set_max_height(Some(3));
+    dialog.set_scroll(10);
+    dialog.draw(&mut text_buffer);
+    let row = |text_buffer: &TextBuffer, y: u32| -> String {
+        (0..8)
+            .map(|x| text_buffer.get_character(x, y).unwrap().get_char())
+            .collect()
+    };
+    assert_eq!(row(&text_buffer, 0), "11 w10  ");
+    assert_eq!(row(&text_buffer, 1), "12 w11  ");
+    assert_eq!(row(&text_buffer, 2), "        ");
+
+    dialog.set_scroll(0);
+    dialog.draw(&mut text_buffer);
+    assert_eq!(row(&text_buffer, 0), " 1 w00  ");
+}
+
+#[test]
+fn highlight() {
+    let mut text_buffer = test_setup_text_buffer((10, 2));
+    let mut dialog = Dialog::new(10, None, None).with_text("cat dog\ncatalog");
+    dialog.update(0.0, &DefaultProcessor);
+    let style = TextStyle::inverted();
+    dialog.highlight("cat", style);
+    dialog.update(0.0, &DefaultProcessor);
+    dialog.draw(&mut text_buffer);
+
+    let highlighted = |text_buffer: &TextBuffer, x: u32, y: u32| {
+        text_buffer.get_character(x, y).unwrap().style == style
+    };
+    for &(x, y) in &[(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)] {
+        assert!(highlighted(&text_buffer, x, y));
+    }
+    for &(x, y) in &[(3, 0), (4, 0), (3, 1), (6, 1)] {
+        assert!(!highlighted(&text_buffer, x, y));
+    }
+
+    dialog.clear_highlight();
+    dialog.update(0.0, &DefaultProcessor);
+    dialog.draw(&mut text_buffer);
+    assert!(!highlighted(&text_buffer, 0, 0));
+}
+
+#[test]
+fn scrollbar() {
+    let mut text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(false);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    let text = (0..10)
+        .map(|idx| format!("w{}", idx))
+        .collect::<Vec<String>>()
+        .join(" ");
+    // The scrollbar takes one column, leaving room for one word per row
+    let mut dialog = Dialog::new(4, 5, 5).with_text(text).with_scrollbar(true);
+    dialog.update(0.0, &DefaultProcessor);
+    assert_eq!(dialog.get_total_height(), 5);
+
+    // Clicking outside the scrollbar does nothing
+    events.cursor.update_location((0.25, 0.45));
+    events.mouse.update_button_press(MouseButton::Left, true);
+    assert!(!dialog.drag_scrollbar(&text_buffer, &events));
+    assert_eq!(dialog.get_scroll(), 0);
+
+    // Clicking the bottom of the track jumps to the last page
+    events.clear_just_lists();
+    events.mouse.update_button_press(MouseButton::Left, false);
+    events.cursor.update_location((0.35, 0.45));
+    events.mouse.update_button_press(MouseButton::Left, true);
+    assert!(dialog.drag_scrollbar(&text_buffer, &events));
+    assert_eq!(dialog.get_scroll(), 5);
+
+    // Dragging to the middle of the track scrolls proportionally
+    events.clear_just_lists();
+    events.cursor.update_location((0.95, 0.25));
+    assert!(dialog.drag_scrollbar(&text_buffer, &events));
+    assert_eq!(dialog.get_scroll(), 3);
+
+    // Releasing the button stops the dragging
+    events.mouse.update_button_press(MouseButton::Left, false);
+    events.cursor.update_location((0.35, 0.05));
+    assert!(!dialog.drag_scrollbar(&text_buffer, &events));
+    assert_eq!(dialog.get_scroll(), 3);
+
+    dialog.scroll_to_bottom();
+    dialog.draw(&mut text_buffer);
+    let column: String = (0..5)
+        .map(|y| text_buffer.get_character(3, y).unwrap().get_char())
+        .collect();
+    assert_eq!(column, "││███");
+    assert_eq!(text_buffer.get_character(0, 0).unwrap().get_char(), 'w');
+}
+
+#[test]
+fn zero_height() {
+    let mut text_buffer = test_setup_text_buffer((10, 2));
+    let mut dialog = Dialog::new(10, None, Some(0)).with_text("hello");
+    dialog.update(0.0, &DefaultProcessor);
+    dialog.draw(&mut text_buffer);
+
+    dialog.set_scrollbar(true);
+    dialog.update(0.0, &DefaultProcessor);
+    dialog.draw(&mut text_buffer);
+    assert_eq!(text_buffer.get_character(0, 0).unwrap().get_char(), ' ');
+}
+
+#[test]
+fn page_navigation() {
+    let text = (0..10)
+        .map(|idx| format!("w{}", idx))
+        .collect::<Vec<String>>()
+        .join(" ");
+    let mut dialog = Dialog::new(3, 3, 3).with_text(text);
+    dialog.update(0.0, &DefaultProcessor);
+
+    assert!(dialog.page_down());
+    assert_eq!(dialog.get_scroll(), 3);
+    assert!(dialog.page_down());
+    assert_eq!(dialog.get_scroll(), 6);
+    assert!(dialog.page_down());
+    assert_eq!(dialog.get_scroll(), 7);
+    assert!(!dialog.page_down());
+
+    assert!(dialog.page_up());
+    assert_eq!(dialog.get_scroll(), 4);
+
+    assert!(dialog.scroll_to_top());
+    assert_eq!(dialog.get_scroll(), 0);
+    assert!(!dialog.page_up());
+
+    let mut events = Events::new(false);
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::End, true);
+    assert!(dialog.handle_events(&events));
+    assert_eq!(dialog.get_scroll(), 7);
+
+    let mut events = Events::new(false);
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Home, true);
+    assert!(dialog.handle_events(&events));
+    assert_eq!(dialog.get_scroll(), 0);
+}
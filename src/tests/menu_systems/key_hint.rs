@@ -0,0 +1,37 @@
+use super::test_setup_text_buffer;
+use crate::menu_systems::{InterfaceItem, KeyHint};
+use crate::{Events, TextStyle, VirtualKeyCode};
+
+#[test]
+fn total_width() {
+    let hint = KeyHint::new(VirtualKeyCode::Return, "Confirm");
+    assert_eq!(hint.get_total_width(), "[Enter] Confirm".len() as u32);
+    assert_eq!(hint.get_total_height(), 1);
+
+    let mut hint = hint;
+    hint.set_description("");
+    assert_eq!(hint.get_total_width(), "[Enter] ".len() as u32);
+}
+
+#[test]
+fn draw_with_styles() {
+    let mut text_buffer = test_setup_text_buffer((20, 2));
+    let key_style = TextStyle::warning();
+    let label_style = TextStyle::inverted();
+    let mut hint = KeyHint::new(VirtualKeyCode::Escape, "Back")
+        .with_pos((1, 1))
+        .with_key_style(key_style)
+        .with_label_style(label_style);
+
+    assert!(!hint.handle_events(&Events::new(true)));
+    assert!(!hint.get_base().can_be_focused);
+    hint.draw(&mut text_buffer);
+
+    let text: String = (1..12)
+        .map(|x| text_buffer.get_character(x, 1).unwrap().get_char())
+        .collect();
+    assert_eq!(text, "[Esc] Back ");
+    assert_eq!(text_buffer.get_character(1, 1).unwrap().style, key_style);
+    assert_eq!(text_buffer.get_character(5, 1).unwrap().style, key_style);
+    assert_eq!(text_buffer.get_character(6, 1).unwrap().style, label_style);
+}
@@ -1,6 +1,10 @@
 use super::{random_text, run_multiple_times, test_setup_text_buffer};
-use crate::menu_systems::{FocusSelection, GrowthDirection, Menu, MenuList, TextItem};
-use crate::{Events, VirtualKeyCode};
+use crate::menu_systems::{
+    Canvas, Checkbox, FocusSelection, GrowthDirection, InterfaceItem, Menu, MenuList, TextInput,
+    TextItem, Theme, Window,
+};
+use crate::{Events, MouseButton, TextBuffer, TextStyle, VirtualKeyCode};
+use std::collections::HashMap;
 
 use rand::{thread_rng, Rng};
 
@@ -38,6 +42,7 @@ fn input_selecting() {
                 GrowthDirection::Up => VirtualKeyCode::Up,
                 GrowthDirection::Left => VirtualKeyCode::Left,
                 GrowthDirection::Right => VirtualKeyCode::Right,
+                GrowthDirection::Grid { .. } => unreachable!(),
             }
         };
 
@@ -50,6 +55,7 @@ fn input_selecting() {
                 GrowthDirection::Up => VirtualKeyCode::Down,
                 GrowthDirection::Left => VirtualKeyCode::Right,
                 GrowthDirection::Right => VirtualKeyCode::Left,
+                GrowthDirection::Grid { .. } => unreachable!(),
             }
         };
 
@@ -135,6 +141,7 @@ fn draw() {
                 up_1 = text1;
                 up_2 = text2;
             }
+            GrowthDirection::Grid { .. } => unreachable!(),
         }
 
         let expected_rows = [
@@ -176,3 +183,628 @@ fn draw() {
         assert_eq!(expected_rows, actual_rows);
     });
 }
+
+#[test]
+fn scrolling() {
+    let mut text_buffer = test_setup_text_buffer((4, 4));
+    let mut menu = Menu::new()
+        .with_pos((0, 1))
+        .with_focus(true)
+        .with_max_visible(2);
+    let mut events = Events::new(false);
+
+    let mut items: Vec<TextItem> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|text| TextItem::new(*text).with_is_button(true))
+        .collect();
+
+    for _ in 0..3 {
+        events
+            .keyboard
+            .update_button_press(VirtualKeyCode::Down, true);
+        let mut list = MenuList::new();
+        for item in &mut items {
+            list.add_item(item, None);
+        }
+        menu.update(&events, 0.0, &text_buffer, &mut list);
+        events.clear_just_lists();
+        events
+            .keyboard
+            .update_button_press(VirtualKeyCode::Down, false);
+    }
+
+    assert_eq!(menu.get_select_idx(), 3);
+    assert_eq!(menu.get_scroll_idx(), 2);
+    assert_eq!(menu.get_cloned_list()[3].get_base().get_pos(), (0, 2));
+
+    menu.draw(&mut text_buffer);
+
+    let column: String = (0..4)
+        .map(|y| text_buffer.get_character(0, y).unwrap().get_char())
+        .collect();
+    assert_eq!(column, "▲cd▼");
+}
+
+#[test]
+fn grid() {
+    let text_buffer = test_setup_text_buffer((6, 4));
+    let mut menu = Menu::new()
+        .with_pos((1, 1))
+        .with_focus(true)
+        .with_growth_direction(GrowthDirection::Grid { cols: 2 });
+    let mut events = Events::new(false);
+
+    let mut items: Vec<TextItem> = ["a", "bb", "c", "d", "e", "f"]
+        .iter()
+        .map(|text| TextItem::new(*text).with_is_button(true))
+        .collect();
+
+    let mut update = |menu: &mut Menu, events: &Events| {
+        let mut list = MenuList::new();
+        for item in &mut items {
+            list.add_item(item, None);
+        }
+        menu.update(events, 0.0, &text_buffer, &mut list);
+    };
+
+    update(&mut menu, &events);
+
+    let positions: Vec<(u32, u32)> = menu
+        .get_cloned_list()
+        .iter()
+        .map(|item| item.get_base().get_pos())
+        .collect();
+    assert_eq!(
+        positions,
+        vec![(1, 1), (2, 1), (1, 2), (2, 2), (1, 3), (2, 3)]
+    );
+
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Down, true);
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 2);
+
+    events.clear_just_lists();
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Down, false);
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Right, true);
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 3);
+}
+
+#[test]
+fn item_spacing() {
+    let text_buffer = test_setup_text_buffer((4, 8));
+    let mut menu = Menu::new().with_pos((0, 1)).with_item_spacing(1);
+    let events = Events::new(false);
+
+    let mut item1 = TextItem::new("a");
+    let mut item2 = TextItem::new("b");
+    let mut item3 = TextItem::new("c");
+
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut item1, None)
+            .with_item(&mut item2, None)
+            .with_item(&mut item3, None),
+    );
+
+    let positions: Vec<u32> = menu
+        .get_cloned_list()
+        .iter()
+        .map(|item| item.get_base().get_pos().1)
+        .collect();
+    assert_eq!(positions, vec![1, 3, 5]);
+}
+
+#[test]
+fn focused_item() {
+    let text_buffer = test_setup_text_buffer((4, 4));
+    let events = Events::new(false);
+    let mut menu = Menu::new();
+
+    let mut label = TextItem::new("label");
+    let mut button1 = TextItem::new("b1").with_is_button(true);
+    let mut button2 = TextItem::new("b2").with_is_button(true);
+
+    let mut update = |menu: &mut Menu| {
+        menu.update(
+            &events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new()
+                .with_item(&mut label, None)
+                .with_item(&mut button1, None)
+                .with_item(&mut button2, None),
+        );
+    };
+
+    update(&mut menu);
+    assert!(menu.focused_item().is_none());
+
+    menu.set_focused(true);
+    update(&mut menu);
+    assert_eq!(menu.get_focused_item_index(), Some(1));
+    let item = menu.focused_item().unwrap();
+    assert!(item.get_base().can_be_focused);
+    assert_eq!(item.get_total_width(), 2);
+}
+
+#[test]
+fn consumed_input() {
+    let text_buffer = test_setup_text_buffer((4, 4));
+    let mut events = Events::new(false);
+    let mut menu = Menu::new().with_focus(true);
+
+    let mut button1 = TextItem::new("b1").with_is_button(true);
+    let mut button2 = TextItem::new("b2").with_is_button(true);
+
+    let mut update = |menu: &mut Menu, events: &Events| {
+        menu.update(
+            events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new()
+                .with_item(&mut button1, None)
+                .with_item(&mut button2, None),
+        );
+    };
+
+    update(&mut menu, &events);
+    assert!(!menu.consumed_input());
+
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Down, true);
+    update(&mut menu, &events);
+    assert!(menu.consumed_input());
+    assert_eq!(menu.get_select_idx(), 1);
+
+    events.clear_just_lists();
+    events.keyboard.update_button_press(VirtualKeyCode::A, true);
+    update(&mut menu, &events);
+    assert!(!menu.consumed_input());
+    assert_eq!(menu.get_select_idx(), 1);
+}
+
+#[test]
+fn focused_claims_directions() {
+    let text_buffer = test_setup_text_buffer((10, 4));
+    let mut events = Events::new(false);
+
+    // An item that consumes Down keeps the selection in place
+    let mut menu = Menu::new().with_focus(true);
+    let mut canvas = Canvas::new(1, 1, |_, _| {})
+        .with_events_handler(|events| events.keyboard.was_just_pressed(VirtualKeyCode::Down));
+    let mut button = TextItem::new("b").with_is_button(true);
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Down, true);
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut canvas, None)
+            .with_item(&mut button, None),
+    );
+    assert!(menu.consumed_input());
+    assert_eq!(menu.get_select_idx(), 0);
+
+    // An item that does not consume Down only keeps it with the option enabled
+    let update = |menu: &mut Menu, events: &Events| {
+        let mut input = TextInput::new(None, 5);
+        let mut button = TextItem::new("b").with_is_button(true);
+        menu.update(
+            events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new()
+                .with_item(&mut input, None)
+                .with_item(&mut button, None),
+        );
+    };
+    let mut menu = Menu::new().with_focus(true);
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 1);
+
+    let mut menu = Menu::new()
+        .with_focus(true)
+        .with_focused_claims_directions(true);
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 0);
+
+    // Non-arrow selection buttons still work
+    menu.set_focus_selection(FocusSelection::Keyboard(None, Some(VirtualKeyCode::Tab)));
+    events.clear_just_lists();
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Tab, true);
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 1);
+}
+
+#[test]
+fn clear_menu_list() {
+    let text_buffer = test_setup_text_buffer((4, 4));
+    let events = Events::new(false);
+    let mut menu = Menu::new().with_focus(true);
+
+    let mut item1 = TextItem::new("1");
+    let mut item2 = TextItem::new("2");
+    let mut item3 = TextItem::new("3");
+
+    let mut list = MenuList::new().with_item(&mut item1, None);
+    assert_eq!(list.len(), 1);
+    menu.update(&events, 0.0, &text_buffer, &mut list);
+    assert_eq!(menu.get_cloned_list().len(), 1);
+
+    list.clear();
+    assert!(list.is_empty());
+    menu.update(&events, 0.0, &text_buffer, &mut list);
+    assert_eq!(menu.get_cloned_list().len(), 0);
+
+    list.add_item(&mut item2, None);
+    list.add_item(&mut item3, None);
+    assert_eq!(list.len(), 2);
+    menu.update(&events, 0.0, &text_buffer, &mut list);
+    assert_eq!(menu.get_cloned_list().len(), 2);
+}
+
+#[test]
+fn invisible_items() {
+    let mut text_buffer = test_setup_text_buffer((4, 4));
+    let mut events = Events::new(false);
+    let mut menu = Menu::new().with_focus(true);
+
+    let mut button1 = TextItem::new("b1").with_is_button(true);
+    let mut button2 = TextItem::new("b2").with_is_button(true).with_visible(false);
+    let mut button3 = TextItem::new("b3").with_is_button(true);
+
+    let mut update = |menu: &mut Menu, events: &Events| {
+        menu.update(
+            events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new()
+                .with_item(&mut button1, None)
+                .with_item(&mut button2, None)
+                .with_item(&mut button3, None),
+        );
+    };
+
+    update(&mut menu, &events);
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Down, true);
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 2);
+
+    events.clear_just_lists();
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Up, true);
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 0);
+
+    // The invisible item takes no space, so the third item is drawn right after the first
+    menu.draw(&mut text_buffer);
+    let row = |text_buffer: &TextBuffer, y: u32| -> String {
+        (0..2)
+            .map(|x| text_buffer.get_character(x, y).unwrap().get_char())
+            .collect()
+    };
+    assert_eq!(row(&text_buffer, 0), "b1");
+    assert_eq!(row(&text_buffer, 1), "b3");
+    assert_eq!(row(&text_buffer, 2), "  ");
+}
+
+#[test]
+fn hover_empty_item() {
+    let text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(false);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    // The spacer is at (0, 0) and the button on the row below it
+    events.cursor.update_location((0.05, 0.05));
+
+    let mut spacer = TextItem::new("");
+    let mut button = TextItem::new("b").with_is_button(true);
+    let mut menu = Menu::new()
+        .with_focus(true)
+        .with_focus_selection(FocusSelection::MouseAndKeyboard(None, None));
+    for _ in 0..2 {
+        menu.update(
+            &events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new()
+                .with_item(&mut spacer, None)
+                .with_item(&mut button, None),
+        );
+    }
+    assert!(!menu.get_cloned_list()[0].get_base().is_hovered());
+
+    events.cursor.update_location((0.05, 0.15));
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut spacer, None)
+            .with_item(&mut button, None),
+    );
+    assert!(menu.get_cloned_list()[1].get_base().is_hovered());
+}
+
+#[test]
+fn downcast_cloned_item() {
+    let text_buffer = test_setup_text_buffer((4, 4));
+    let events = Events::new(false);
+    let mut menu = Menu::new();
+
+    let mut item = TextItem::new("text");
+    let mut checkbox = Checkbox::new("check");
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut item, None)
+            .with_item(&mut checkbox, None),
+    );
+
+    let list = menu.get_cloned_list();
+    let item = list[0].as_any().downcast_ref::<TextItem>().unwrap();
+    assert_eq!(item.get_text(), "text");
+    assert!(list[1].as_any().downcast_ref::<TextItem>().is_none());
+    assert!(list[1].as_any().downcast_ref::<Checkbox>().is_some());
+}
+
+#[test]
+fn hovered() {
+    let text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(true);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    let mut menu = Menu::new().with_focus(true);
+
+    let mut button1 = TextItem::new("b1").with_is_button(true);
+    let mut button2 = TextItem::new("b2").with_is_button(true);
+
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut button1, None)
+            .with_item(&mut button2, None),
+    );
+    assert!(!button1.get_base().is_hovered());
+    assert!(!button2.get_base().is_hovered());
+
+    // Move the cursor on top of the second button, on the second row
+    events.cursor.update_location((0.05, 0.15));
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut button1, None)
+            .with_item(&mut button2, None),
+    );
+    assert!(!button1.get_base().is_hovered());
+    assert!(button2.get_base().is_hovered());
+    assert!(button1.get_base().is_focused());
+    assert!(!button2.get_base().is_focused());
+}
+
+#[test]
+fn right_clicked_item() {
+    let text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(true);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    let mut menu = Menu::new();
+
+    let mut label = TextItem::new("label");
+    let mut button = TextItem::new("button").with_is_button(true);
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut label, None)
+            .with_item(&mut button, None),
+    );
+
+    events.cursor.update_location((0.25, 0.15));
+    assert_eq!(menu.right_clicked_item(&events, &text_buffer), None);
+
+    events.mouse.update_button_press(MouseButton::Right, true);
+    assert_eq!(menu.right_clicked_item(&events, &text_buffer), Some(1));
+
+    // Items that can't be focused can be right-clicked too
+    events.cursor.update_location((0.25, 0.05));
+    assert_eq!(menu.right_clicked_item(&events, &text_buffer), Some(0));
+
+    events.cursor.update_location((0.95, 0.95));
+    assert_eq!(menu.right_clicked_item(&events, &text_buffer), None);
+}
+
+#[test]
+fn center_in() {
+    let text_buffer = test_setup_text_buffer((20, 20));
+    let events = Events::new(false);
+    let mut menu = Menu::new();
+
+    let mut item1 = TextItem::new("abcd");
+    let mut item2 = TextItem::new("ab");
+    let mut update = |menu: &mut Menu| {
+        menu.update(
+            &events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new()
+                .with_item(&mut item1, None)
+                .with_item(&mut item2, None),
+        );
+    };
+
+    update(&mut menu);
+    assert_eq!(menu.get_total_width(), 4);
+    assert_eq!(menu.get_total_height(), 2);
+
+    menu.center_in((20, 20));
+    assert_eq!(menu.get_pos(), (8, 9));
+
+    update(&mut menu);
+    let positions: Vec<(u32, u32)> = menu
+        .get_cloned_list()
+        .iter()
+        .map(|item| item.get_base().get_pos())
+        .collect();
+    assert_eq!(positions, vec![(8, 9), (8, 10)]);
+}
+
+#[test]
+fn draw_in_window() {
+    let mut text_buffer = test_setup_text_buffer((10, 6));
+    let events = Events::new(false);
+    let window = Window::new(4, 2).with_pos((2, 1));
+    let mut menu = Menu::new();
+
+    let mut item1 = TextItem::new("ab");
+    let mut item2 = TextItem::new("cd");
+    // Too wide to fit inside the window
+    let mut item3 = TextItem::new("toolong");
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut item1, None)
+            .with_item(&mut item2, None)
+            .with_item(&mut item3, None),
+    );
+
+    window.draw(&mut text_buffer);
+    menu.draw_in_window(&window, &mut text_buffer);
+
+    let row = |text_buffer: &crate::TextBuffer, y: u32| -> String {
+        (0..10)
+            .map(|x| text_buffer.get_character(x, y).unwrap().get_char())
+            .collect()
+    };
+    assert_eq!(row(&text_buffer, 1), "  ╔════╗  ");
+    assert_eq!(row(&text_buffer, 2), "  ║ab  ║  ");
+    assert_eq!(row(&text_buffer, 3), "  ║cd  ║  ");
+    assert_eq!(row(&text_buffer, 4), "  ╚════╝  ");
+    assert_eq!(row(&text_buffer, 5), "          ");
+
+    // The items are moved inside the window
+    assert_eq!(menu.get_cloned_list()[0].get_base().get_pos(), (3, 2));
+    menu.clear_window();
+    assert_eq!(menu.get_cloned_list()[0].get_base().get_pos(), (0, 0));
+}
+
+#[test]
+fn hover_in_window() {
+    let text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(false);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    let window = Window::new(6, 3).with_pos((2, 1));
+    let mut menu = Menu::new()
+        .with_focus(true)
+        .with_focus_selection(FocusSelection::MouseAndKeyboard(None, None))
+        .with_window(&window);
+
+    let mut item1 = TextItem::new("ab").with_is_button(true);
+    let mut item2 = TextItem::new("cd").with_is_button(true);
+    // Too wide to fit inside the window
+    let mut item3 = TextItem::new("toolong").with_is_button(true);
+    let mut update = |menu: &mut Menu, events: &Events| {
+        menu.update(
+            events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new()
+                .with_item(&mut item1, None)
+                .with_item(&mut item2, None)
+                .with_item(&mut item3, None),
+        );
+    };
+
+    update(&mut menu, &events);
+
+    // The second item is drawn at (3, 3), inside the window
+    events.cursor.update_location((0.35, 0.35));
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 1);
+
+    // The position of the second item without the window is not hovered
+    events.cursor.update_location((0.05, 0.15));
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 1);
+
+    // The item that does not fit can not be hovered
+    events.cursor.update_location((0.35, 0.45));
+    update(&mut menu, &events);
+    assert_eq!(menu.get_select_idx(), 1);
+}
+
+#[test]
+fn theme() {
+    let mut text_buffer = test_setup_text_buffer((10, 2));
+    let events = Events::new(false);
+    let theme = Theme {
+        text: TextStyle::warning(),
+        focused: TextStyle::error(),
+        ..Default::default()
+    };
+    let mut menu = Menu::new().with_focus(true).with_theme(theme);
+
+    let mut button1 = TextItem::new("b1").with_is_button(true);
+    let mut button2 = TextItem::new("b2").with_is_button(true);
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut button1, None)
+            .with_item(&mut button2, None),
+    );
+    assert_eq!(button2.unfocused_style, theme.text);
+    menu.draw(&mut text_buffer);
+
+    assert_eq!(
+        text_buffer.get_character(0, 0).unwrap().style,
+        theme.focused
+    );
+    assert_eq!(text_buffer.get_character(0, 1).unwrap().style, theme.text);
+
+    // Without a theme, the items keep the styles they have
+    menu.set_theme(None);
+    button1.unfocused_style = TextStyle::inverted();
+    menu.update(
+        &events,
+        0.0,
+        &text_buffer,
+        &mut MenuList::new()
+            .with_item(&mut button1, None)
+            .with_item(&mut button2, None),
+    );
+    assert_eq!(button1.unfocused_style, TextStyle::inverted());
+}
@@ -0,0 +1,48 @@
+use super::test_setup_text_buffer;
+use crate::menu_systems::{Menu, MenuList, MenuSwitcher, TextItem};
+use crate::{Events, VirtualKeyCode};
+
+#[test]
+fn just_switched() {
+    let text_buffer = test_setup_text_buffer((4, 4));
+    let mut events = Events::new(false);
+
+    let mut menu1 = Menu::new();
+    let mut menu2 = Menu::new().with_pos((2, 0));
+    let mut switcher = MenuSwitcher::new(VirtualKeyCode::Left, VirtualKeyCode::Right);
+
+    let mut item1 = TextItem::new("a").with_is_button(true);
+    let mut item2 = TextItem::new("b").with_is_button(true);
+
+    let mut update = |events: &Events, switcher: &mut MenuSwitcher| {
+        menu1.update(
+            events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new().with_item(&mut item1, None),
+        );
+        menu2.update(
+            events,
+            0.0,
+            &text_buffer,
+            &mut MenuList::new().with_item(&mut item2, None),
+        );
+        switcher.update(events, &mut [&mut menu1, &mut menu2]);
+    };
+
+    update(&events, &mut switcher);
+    assert!(!switcher.just_switched());
+    assert_eq!(switcher.get_focused_menu_idx(), 0);
+
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::Right, true);
+    update(&events, &mut switcher);
+    assert!(switcher.just_switched());
+    assert_eq!(switcher.get_focused_menu_idx(), 1);
+
+    events.clear_just_lists();
+    update(&events, &mut switcher);
+    assert!(!switcher.just_switched());
+    assert_eq!(switcher.get_focused_menu_idx(), 1);
+}
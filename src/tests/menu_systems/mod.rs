@@ -4,11 +4,15 @@ use crate::{MouseButton, VirtualKeyCode, TextStyle};
 
 use rand::{thread_rng, Rng};
 
+mod canvas;
 mod checkbox;
 mod dialog;
+mod key_hint;
 mod menu;
+mod menu_switcher;
 mod text_input;
 mod text_item;
+mod window;
 
 #[test]
 fn with_set_macros() {
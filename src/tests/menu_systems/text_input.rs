@@ -2,7 +2,7 @@ use super::{random_text, run_multiple_times, test_setup_text_buffer};
 use crate::menu_systems::{Filter, InterfaceItem, TextInput};
 use crate::text_processing::DefaultProcessor;
 use crate::Events;
-use crate::VirtualKeyCode::Return;
+use crate::VirtualKeyCode::{Down, LShift, Left, Return, Right, Up};
 
 use rand::{thread_rng, Rng};
 
@@ -169,3 +169,223 @@ fn min_max_width() {
         assert_eq!(item.get_total_width(), len as u32);
     });
 }
+
+#[test]
+fn shift_right_selection() {
+    let mut events = Events::new(false);
+    let mut item = TextInput::new(None, None)
+        .with_text("abc")
+        .with_focused(true);
+
+    events.keyboard.update_button_press(Left, true);
+    item.handle_events(&events);
+    events.keyboard.update_button_press(Left, false);
+    events.clear_just_lists();
+    events.keyboard.update_button_press(Left, true);
+    item.handle_events(&events);
+    events.keyboard.update_button_press(Left, false);
+    events.clear_just_lists();
+    assert_eq!(item.get_caret_idx(), 1);
+    assert_eq!(item.get_selection(), None);
+
+    events.keyboard.update_button_press(LShift, true);
+    events.keyboard.update_button_press(Right, true);
+    item.handle_events(&events);
+
+    assert_eq!(item.get_selection(), Some((1, 2)));
+    assert_eq!(item.get_selected_text(), Some("b".to_owned()));
+}
+
+#[test]
+fn typing_replaces_selection() {
+    let mut events = Events::new(false);
+    let mut item = TextInput::new(None, None)
+        .with_text("abcd")
+        .with_filter(Filter::empty_filter().with_basic_latin_characters())
+        .with_focused(true);
+
+    events.keyboard.update_button_press(LShift, true);
+    for _ in 0..2 {
+        events.keyboard.update_button_press(Left, true);
+        item.handle_events(&events);
+        events.keyboard.update_button_press(Left, false);
+        events.clear_just_lists();
+    }
+    assert_eq!(item.get_selection(), Some((2, 4)));
+
+    events.keyboard.update_button_press(LShift, false);
+    events.chars.add_char('x');
+    item.handle_events(&events);
+
+    assert_eq!(item.get_text(), "abx");
+    assert_eq!(item.get_selection(), None);
+    assert_eq!(item.get_caret_idx(), 3);
+}
+
+#[test]
+fn draw_selection() {
+    let mut text_buffer = test_setup_text_buffer((10, 1));
+    let mut events = Events::new(false);
+    let mut item = TextInput::new(None, None)
+        .with_text("abc")
+        .with_caret(0.0)
+        .with_focused(true);
+
+    events.keyboard.update_button_press(LShift, true);
+    events.keyboard.update_button_press(Left, true);
+    item.handle_events(&events);
+    item.update(0.0, &DefaultProcessor);
+    item.draw(&mut text_buffer);
+
+    let selected = text_buffer.get_character(2, 0).unwrap();
+    assert_eq!(selected.get_char(), 'c');
+    assert_eq!(selected.style, item.selection_style);
+    let unselected = text_buffer.get_character(1, 0).unwrap();
+    assert_eq!(unselected.style, item.focused_style);
+}
+
+#[test]
+fn draw_caret_inside_text() {
+    let mut text_buffer = test_setup_text_buffer((10, 1));
+    let mut events = Events::new(false);
+    let mut item = TextInput::new(None, None)
+        .with_text("abc")
+        .with_caret(0.1)
+        .with_focused(true);
+
+    events.keyboard.update_button_press(Left, true);
+    item.handle_events(&events);
+    item.update(0.1, &DefaultProcessor);
+    assert!(item.caret_showing());
+    item.draw(&mut text_buffer);
+
+    // The character under the caret is kept, with its colors inverted
+    let under_caret = text_buffer.get_character(2, 0).unwrap();
+    assert_eq!(under_caret.get_char(), 'c');
+    assert_eq!(under_caret.style.fg_color, item.focused_style.bg_color);
+    assert_eq!(under_caret.style.bg_color, item.focused_style.fg_color);
+    let other = text_buffer.get_character(1, 0).unwrap();
+    assert_eq!(other.get_char(), 'b');
+    assert_eq!(other.style, item.focused_style);
+}
+
+#[test]
+fn placeholder() {
+    let mut text_buffer = test_setup_text_buffer((10, 1));
+    let mut item = TextInput::new(None, None)
+        .with_placeholder("Search")
+        .with_caret(0.0);
+    item.update(0.0, &DefaultProcessor);
+    item.draw(&mut text_buffer);
+
+    assert_eq!(item.get_text(), "");
+    assert_eq!(item.get_total_width(), 6);
+    let text: String = (0..6)
+        .map(|x| text_buffer.get_character(x, 0).unwrap().get_char())
+        .collect();
+    assert_eq!(text, "Search");
+    let character = text_buffer.get_character(0, 0).unwrap();
+    assert_eq!(character.style.fg_color, item.placeholder_color);
+    assert_eq!(character.style.bg_color, item.unfocused_style.bg_color);
+
+    let mut text_buffer = test_setup_text_buffer((10, 1));
+    item.set_text("ab");
+    item.update(0.0, &DefaultProcessor);
+    item.draw(&mut text_buffer);
+
+    assert_eq!(item.get_total_width(), 2);
+    let text: String = (0..6)
+        .map(|x| text_buffer.get_character(x, 0).unwrap().get_char())
+        .collect();
+    assert_eq!(text, "ab    ");
+    let character = text_buffer.get_character(0, 0).unwrap();
+    assert_eq!(character.style, item.unfocused_style);
+}
+
+#[test]
+fn take_text() {
+    let mut item = TextInput::new(None, None).with_text("hello");
+    assert_eq!(item.take_text(), "hello");
+    assert_eq!(item.get_text(), "");
+    assert_eq!(item.get_caret_idx(), 0);
+    // History is disabled by default
+    assert!(item.get_history().is_empty());
+}
+
+#[test]
+fn history_recall() {
+    let press = |item: &mut TextInput, button| {
+        let mut events = Events::new(false);
+        events.keyboard.update_button_press(button, true);
+        item.handle_events(&events)
+    };
+
+    let mut item = TextInput::new(None, None)
+        .with_history(2)
+        .with_focused(true);
+    for text in &["first", "second", "third"] {
+        item.set_text(*text);
+        item.take_text();
+    }
+    assert_eq!(item.get_history(), vec!["second", "third"]);
+
+    item.set_text("draft");
+    assert!(press(&mut item, Up));
+    assert_eq!(item.get_text(), "third");
+    assert!(press(&mut item, Up));
+    assert_eq!(item.get_text(), "second");
+    // The end of the history still consumes the arrow
+    assert!(press(&mut item, Up));
+    assert_eq!(item.get_text(), "second");
+
+    assert!(press(&mut item, Down));
+    assert_eq!(item.get_text(), "third");
+    assert!(press(&mut item, Down));
+    assert_eq!(item.get_text(), "draft");
+    assert!(press(&mut item, Down));
+    assert_eq!(item.get_text(), "draft");
+
+    // Without history the arrows are not consumed
+    let mut item = TextInput::new(None, None).with_focused(true);
+    assert!(!press(&mut item, Up));
+}
+
+#[test]
+fn submit_on_press() {
+    let mut events = Events::new(false);
+    events.keyboard.update_button_press(Return, true);
+
+    let mut item = TextInput::new(None, None)
+        .with_submit_on_press(true)
+        .with_focused(true)
+        .with_text("hello");
+    assert!(item.handle_events(&events));
+    assert!(item.was_just_pressed());
+    assert_eq!(item.get_submitted_text(), Some("hello".to_owned()));
+    assert_eq!(item.get_text(), "");
+    // History is disabled by default
+    assert!(item.get_history().is_empty());
+
+    // The submitted text only lasts for one handle_events
+    item.handle_events(&Events::new(false));
+    assert_eq!(item.get_submitted_text(), None);
+
+    // With history, the submitted text is added to it
+    let mut item = TextInput::new(None, None)
+        .with_submit_on_press(true)
+        .with_history(5)
+        .with_focused(true)
+        .with_text("hello");
+    item.handle_events(&events);
+    assert_eq!(item.get_history(), vec!["hello"]);
+
+    // Without submitting on press, pressing does not take the text, even with history
+    let mut item = TextInput::new(None, None)
+        .with_history(5)
+        .with_focused(true)
+        .with_text("hello");
+    item.handle_events(&events);
+    assert!(item.was_just_pressed());
+    assert_eq!(item.get_submitted_text(), None);
+    assert_eq!(item.get_text(), "hello");
+}
@@ -0,0 +1,58 @@
+use super::test_setup_text_buffer;
+use crate::menu_systems::Window;
+use crate::{Events, MouseButton};
+use std::collections::HashMap;
+
+#[test]
+fn corner_hit() {
+    let text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(true);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    let window = Window::new(2, 3).with_pos((1, 1));
+
+    // The bottom right corner is at (4, 5)
+    events.cursor.update_location((0.45, 0.55));
+    assert!(!window.corner_hit(&text_buffer, &events));
+    events.mouse.update_button_press(MouseButton::Left, true);
+    assert!(window.corner_hit(&text_buffer, &events));
+
+    // Other border cells are not the corner
+    for location in &[(0.35, 0.55), (0.45, 0.45), (0.15, 0.15), (0.55, 0.65)] {
+        events.cursor.update_location(*location);
+        assert!(!window.corner_hit(&text_buffer, &events));
+    }
+
+    // Holding the button is not enough
+    events.cursor.update_location((0.45, 0.55));
+    events.clear_just_lists();
+    assert!(!window.corner_hit(&text_buffer, &events));
+}
+
+#[test]
+fn drag_and_resize() {
+    let text_buffer = test_setup_text_buffer((10, 10));
+    let mut events = Events::new(true);
+    events
+        .cursor
+        .update_display_datas((0.0, 0.0), (1.0, 1.0), HashMap::new());
+    let mut window = Window::new(2, 3)
+        .with_pos((1, 1))
+        .with_vertical_split(1)
+        .with_vertical_split(2);
+
+    events.cursor.update_location((0.75, 0.85));
+    assert_eq!(window.drag_size(&text_buffer, &events), Some((5, 6)));
+
+    // Dragging past the top left corner keeps the window at least 1x1
+    events.cursor.update_location((0.05, 0.05));
+    let size = window.drag_size(&text_buffer, &events).unwrap();
+    assert_eq!(size, (1, 1));
+
+    window.resize_to(size);
+    assert_eq!((window.width, window.height), (1, 1));
+    events.cursor.update_location((0.35, 0.35));
+    events.mouse.update_button_press(MouseButton::Left, true);
+    assert!(window.corner_hit(&text_buffer, &events));
+}
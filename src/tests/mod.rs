@@ -2,11 +2,13 @@ use crate::renderer;
 use crate::terminal::{Terminal, TerminalBuilder};
 use crate::text_buffer::{Color, TextBuffer};
 
+mod animation;
 mod events;
 mod font;
 mod parser;
 mod terminal;
 mod text_buffer;
+mod text_processing;
 
 #[cfg(feature = "menu_systems")]
 mod menu_systems;
@@ -31,13 +33,29 @@ fn gl_error() {
 }
 
 fn test_setup_open_terminal() -> Terminal {
-    TerminalBuilder::new()
+    test_setup_builder()
         .with_title("Simple window")
         .with_dimensions((1280, 720))
-        .with_headless(true)
         .build()
 }
 
+/// A headless builder that has a font, even when the font is not bundled
+fn test_setup_builder() -> TerminalBuilder {
+    let builder = TerminalBuilder::new().with_headless(true);
+    #[cfg(not(feature = "bundled_font"))]
+    let builder = builder.with_font(test_setup_font());
+    builder
+}
+
+#[cfg(not(feature = "bundled_font"))]
+fn test_setup_font() -> crate::Font {
+    crate::Font::load_raw(
+        &crate::FontFormat::SFL,
+        include_str!("../../fonts/source_code_pro.sfl"),
+        &include_bytes!("../../fonts/source_code_pro.png")[..],
+    )
+}
+
 fn test_setup_text_buffer(dimensions: (u32, u32)) -> TextBuffer {
     let terminal = test_setup_open_terminal();
     match TextBuffer::create(&terminal, dimensions) {
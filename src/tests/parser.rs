@@ -1,4 +1,5 @@
 use super::{random_color, run_multiple_times, test_setup_text_buffer};
+use crate::text_buffer::parser::MAX_VAR_EXPANDED_LEN;
 use crate::{Parser, TextStyle};
 
 #[test]
@@ -52,3 +53,54 @@ fn write() {
         );
     });
 }
+
+#[test]
+fn visible_len() {
+    let mut parser = Parser::new();
+    parser.add_color("red", [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(parser.visible_len("[fg=red]abc[/fg]"), 3);
+    assert_eq!(parser.visible_len("[shake=0.5]ab[/shake]c[bg=red]"), 3);
+    assert_eq!(parser.visible_len(""), 0);
+
+    assert_eq!(
+        parser.visible_lines("[fg=red]ab[/fg]\ncde\n"),
+        vec!["ab".to_owned(), "cde".to_owned(), "".to_owned()]
+    );
+}
+
+#[test]
+fn vars() {
+    let mut parser = Parser::new();
+    let color = random_color();
+    parser.add_color("test", color);
+    parser.add_var("name", "Alice");
+    parser.add_var("greeting", "Hello, [fg=test][var=name][/fg]!");
+
+    assert_eq!(parser.visible_lines("[var=name]"), vec!["Alice"]);
+    let parsed = parser.parse("[var=greeting]");
+    let text: String = parsed.iter().map(|c| c.character).collect();
+    assert_eq!(text, "Hello, Alice!");
+    assert_eq!(parsed[7].style.fg_color, Some(color));
+    assert_eq!(parsed[12].style.fg_color, None);
+
+    // Undefined variables are replaced with the placeholder
+    assert_eq!(parser.visible_len("a[var=missing]b"), 2);
+    parser.set_var_placeholder("?");
+    assert_eq!(parser.visible_lines("a[var=missing]b"), vec!["a?b"]);
+
+    // Variables referring to themselves don't recurse forever
+    parser.add_var("loop", "x[var=loop]");
+    assert_eq!(parser.visible_lines("[var=loop]"), vec!["xxxxxxxx?"]);
+}
+
+#[test]
+fn vars_growth_is_capped() {
+    let mut parser = Parser::new();
+    let value = format!("[var=big]{}[var=big]", "x".repeat(1000));
+    parser.add_var("big", value);
+
+    // Without the cap, the text would double with every level of expansion
+    let len = parser.visible_len("[var=big]");
+    assert!(len > 1000);
+    assert!(len <= MAX_VAR_EXPANDED_LEN);
+}
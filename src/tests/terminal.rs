@@ -1,4 +1,8 @@
-use super::test_setup_open_terminal;
+use super::{test_setup_builder, test_setup_open_terminal, test_setup_text_buffer_with_terminal};
+use crate::display::Display;
+use crate::renderer;
+use crate::terminal::Timer;
+use crate::{BlendMode, Events, FrameStats, TerminalBuilder, VirtualKeyCode};
 
 #[test]
 fn open_refresh_and_close() {
@@ -26,3 +30,410 @@ fn programs_debug_shaders() {
         assert_ne!(background_program, debug_background_program);
     }
 }
+
+#[test]
+fn window_size_limits() {
+    let builder = test_setup_builder()
+        .with_resizable(false)
+        .with_min_dimensions((320, 240))
+        .with_max_dimensions((1920, 1080));
+
+    assert!(!builder.resizable);
+    assert_eq!(builder.min_dimensions, Some((320, 240)));
+    assert_eq!(builder.max_dimensions, Some((1920, 1080)));
+
+    let terminal = builder.build();
+    terminal.set_resizable(true);
+    terminal.set_min_size(None);
+    terminal.set_max_size(Some((800, 600)));
+}
+
+#[test]
+fn cursor_visibility_and_grab() {
+    let terminal = test_setup_open_terminal();
+    assert_eq!(terminal.set_cursor_visible(false), Ok(()));
+    assert_eq!(terminal.set_cursor_grab(true), Ok(()));
+    assert_eq!(terminal.set_cursor_grab(false), Ok(()));
+    assert_eq!(terminal.set_cursor_visible(true), Ok(()));
+}
+
+#[test]
+fn run_until_closed() {
+    let terminal = test_setup_open_terminal();
+    let mut frames = 0;
+    terminal.run(|terminal, _| {
+        frames += 1;
+        if frames == 3 {
+            terminal.close();
+        }
+    });
+    assert_eq!(frames, 3);
+}
+
+#[test]
+fn smoothed_delta() {
+    let mut timer = Timer::new();
+    assert_eq!(timer.get_smoothed_delta(), 0.0);
+
+    timer.push_delta(0.5);
+    timer.push_delta(0.25);
+    assert_eq!(timer.get_delta_time(), 0.25);
+    assert_eq!(timer.get_smoothed_delta(), 0.375);
+
+    // Old deltas are dropped once the buffer is full
+    for _ in 0..100 {
+        timer.push_delta(0.125);
+    }
+    assert_eq!(timer.get_smoothed_delta(), 0.125);
+}
+
+#[test]
+fn frame_stats() {
+    let mut timer = Timer::new();
+    assert_eq!(timer.get_frame_stats(), FrameStats::default());
+
+    for delta in &[0.5, 0.125, 0.25] {
+        timer.push_delta(*delta);
+    }
+    let stats = timer.get_frame_stats();
+    assert_eq!(stats.min, 0.125);
+    assert_eq!(stats.max, 0.5);
+    assert_eq!(stats.avg, 0.875 / 3.0);
+    assert_eq!(stats.p99, 0.5);
+
+    // 99 short frames and one long frame
+    let mut timer = Timer::new();
+    for _ in 0..99 {
+        timer.push_delta(0.25);
+    }
+    timer.push_delta(2.0);
+    let stats = timer.get_frame_stats();
+    assert_eq!(stats.p99, 0.25);
+    assert_eq!(stats.max, 2.0);
+    assert_eq!(stats.avg, (99.0 * 0.25 + 2.0) / 100.0);
+
+    // Old deltas are dropped once the window is full
+    for _ in 0..240 {
+        timer.push_delta(0.125);
+    }
+    let stats = timer.get_frame_stats();
+    assert_eq!((stats.min, stats.max, stats.p99), (0.125, 0.125, 0.125));
+}
+
+#[test]
+fn cell_and_grid_pixel_size() {
+    let terminal = test_setup_open_terminal();
+    let xadvance = terminal.font.average_xadvance;
+
+    assert_eq!(terminal.cell_pixel_size(), (xadvance as u32, 77));
+    assert_eq!(
+        terminal.grid_pixel_size(80, 24),
+        ((80.0 * xadvance) as u32, 24 * 77)
+    );
+}
+
+#[test]
+fn snapped_area() {
+    // Aspect ratio 2 in a 1000x700 window leaves a 1000x500 area
+    assert_eq!(Display::calc_area(1000.0, 700.0, 2.0), (1000.0, 500.0));
+    assert_eq!(
+        Display::calc_snapped_area(1000.0, 700.0, 2.0, (30, 7)),
+        (990.0, 497.0)
+    );
+    // Already aligned areas are left alone
+    assert_eq!(
+        Display::calc_snapped_area(1000.0, 700.0, 2.0, (10, 5)),
+        (1000.0, 500.0)
+    );
+}
+
+#[test]
+fn snap_to_cells() {
+    let terminal = test_setup_builder().with_snap_to_cells(true).build();
+    assert!(terminal.is_snapping_to_cells());
+    assert!(!test_setup_open_terminal().is_snapping_to_cells());
+
+    // Snapping only applies with the TextBuffer aspect ratio
+    let terminal = test_setup_builder()
+        .with_snap_to_cells(true)
+        .with_text_buffer_aspect_ratio(false)
+        .build();
+    assert!(!terminal.is_snapping_to_cells());
+}
+
+#[test]
+fn debug_key() {
+    let terminal = test_setup_open_terminal();
+    terminal.set_debug_key(Some(VirtualKeyCode::F5));
+
+    let mut events = Events::new(true);
+    events
+        .keyboard
+        .update_button_press(VirtualKeyCode::F5, true);
+
+    assert!(!terminal.is_debug());
+    terminal.handle_debug_key(&events);
+    assert!(terminal.is_debug());
+    terminal.handle_debug_key(&events);
+    assert!(!terminal.is_debug());
+
+    terminal.set_debug_key(None);
+    terminal.handle_debug_key(&events);
+    assert!(!terminal.is_debug());
+}
+
+#[test]
+fn draw_grid_overlay() {
+    let (text_buffer, terminal) = test_setup_text_buffer_with_terminal((12, 4));
+    terminal.draw_grid_overlay(&text_buffer);
+    terminal.draw_grid_overlay(&text_buffer);
+
+    let overlay = terminal.grid_overlay.borrow();
+    let overlay = overlay.as_ref().unwrap();
+    assert_eq!(overlay.get_dimensions(), (12, 4));
+    assert_eq!(overlay.get_character(11, 0).unwrap().get_char(), '1');
+    assert_eq!(overlay.get_character(0, 3).unwrap().get_char(), '3');
+    assert_eq!(overlay.get_character(5, 2).unwrap().get_char(), ' ');
+}
+
+#[test]
+fn set_title_only_when_changed() {
+    let mut terminal = test_setup_open_terminal();
+
+    // Same as the title given in the builder
+    terminal.set_title("Simple window");
+    assert_eq!(terminal.title_updates, 0);
+
+    terminal.set_title("Another title");
+    terminal.set_title("Another title");
+    assert_eq!(terminal.title_updates, 1);
+}
+
+#[test]
+fn draw_region() {
+    let (text_buffer, terminal) = test_setup_text_buffer_with_terminal((8, 4));
+    terminal.draw_region(&text_buffer, (2, 1, 4, 2), (0.5, 0.0, 0.5, 0.5));
+
+    // The right half of the top half of a 800x600 window, in GL coordinates
+    assert_eq!(
+        renderer::calc_scissor((800.0, 600.0), (0.5, 0.0, 0.5, 0.5)),
+        (400, 300, 400, 300)
+    );
+
+    // The corners of the source cells end up in the corners of the destination
+    let matrix = renderer::create_region_proj_matrix((8, 4), (2, 1, 4, 2), (0.5, 0.0, 0.5, 0.5));
+    let project = |x: f32, y: f32| (matrix[0] * x + matrix[3], matrix[5] * y + matrix[7]);
+    assert_eq!(project(0.25, 0.25), (0.0, 1.0));
+    assert_eq!(project(0.75, 0.75), (1.0, 0.0));
+}
+
+#[test]
+fn draw_clipped() {
+    let (text_buffer, terminal) = test_setup_text_buffer_with_terminal((8, 4));
+    terminal.draw_clipped(&text_buffer, (1, 1, 2, 2));
+
+    // A 8x4 TextBuffer in a 1000x400 window with 100 pixels of black bars on the left and right
+    let rect = renderer::calc_cells_rect((8, 4), (2, 1, 4, 2), (0.1, 0.0), (1.25, 1.0));
+    assert_eq!(
+        renderer::calc_scissor((1000.0, 400.0), rect),
+        (300, 100, 400, 200)
+    );
+}
+
+#[test]
+fn set_cursor_position() {
+    let (text_buffer, terminal) = test_setup_text_buffer_with_terminal((8, 4));
+    assert!(terminal.set_cursor_position((1, 1), &text_buffer).is_ok());
+
+    // A 8x4 TextBuffer in a 1000x400 window with 100 pixels of black bars on the left and right,
+    // so every cell is 100x100 pixels
+    let rect = renderer::calc_cells_rect((8, 4), (2, 1, 1, 1), (0.1, 0.0), (1.25, 1.0));
+    assert_eq!(
+        renderer::calc_rect_center((1000.0, 400.0), rect),
+        (350, 150)
+    );
+    let rect = renderer::calc_cells_rect((8, 4), (0, 3, 1, 1), (0.1, 0.0), (1.25, 1.0));
+    assert_eq!(
+        renderer::calc_rect_center((1000.0, 400.0), rect),
+        (150, 350)
+    );
+}
+
+#[test]
+fn shake_params() {
+    let terminal = test_setup_builder().with_shake_params(0.5, 2.0).build();
+    assert_eq!(terminal.get_shake_params(), (0.5, 2.0));
+    terminal.set_shake_params(3.0, 0.25);
+    assert_eq!(terminal.get_shake_params(), (3.0, 0.25));
+
+    assert_eq!(TerminalBuilder::new().shake_params, (1.0, 1.0));
+}
+
+#[test]
+#[cfg(feature = "bundled_font")]
+fn build_with_bundled_font() {
+    let builder = TerminalBuilder::new().with_headless(true);
+    assert!(builder.font.line_height > 0);
+    let terminal = builder.build();
+    assert_eq!(terminal.font.name, "Source Code Pro");
+}
+
+#[test]
+fn gl_debug() {
+    let builder = test_setup_builder();
+    assert!(!builder.gl_debug);
+    let builder = builder.with_gl_debug(true);
+    assert!(builder.gl_debug);
+
+    // Debug output is never enabled without a display
+    let terminal = builder.build();
+    assert!(!terminal.is_gl_debug());
+}
+
+#[test]
+fn font() {
+    let terminal = test_setup_open_terminal();
+    assert!(terminal.font().line_height > 0);
+    assert_eq!(terminal.font().line_height, terminal.font.line_height);
+    assert_eq!(terminal.font().name, terminal.font.name);
+}
+
+#[test]
+#[cfg(not(feature = "bundled_font"))]
+#[should_panic(expected = "with_font")]
+fn build_without_font() {
+    TerminalBuilder::new().with_headless(true).build();
+}
+
+#[test]
+#[cfg(not(feature = "bundled_font"))]
+fn try_build_without_font() {
+    let result = TerminalBuilder::new().with_headless(true).try_build();
+    assert!(result.err().unwrap().contains("with_font"));
+}
+
+#[test]
+fn elapsed() {
+    let terminal = test_setup_open_terminal();
+    let first = terminal.elapsed();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    let second = terminal.elapsed();
+    assert!(first >= 0.0);
+    assert!(second - first >= 0.015);
+}
+
+#[test]
+fn process_pending_events_and_present() {
+    let mut terminal = test_setup_open_terminal();
+    if !terminal.headless {
+        terminal.update_virtual_keycode(VirtualKeyCode::A, true);
+    }
+
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    assert!(terminal.process_pending_events());
+    assert!(terminal.delta_time() > 0.0);
+    if !terminal.headless {
+        let events = terminal.get_current_events();
+        assert!(events.keyboard.is_pressed(VirtualKeyCode::A));
+        assert!(!events.keyboard.was_just_pressed(VirtualKeyCode::A));
+    }
+    terminal.present();
+
+    terminal.close();
+    assert!(!terminal.process_pending_events());
+}
+
+#[test]
+fn draw_with_blend() {
+    let (text_buffer, terminal) = test_setup_text_buffer_with_terminal((4, 4));
+    for blend_mode in &[BlendMode::Alpha, BlendMode::Additive, BlendMode::Multiply] {
+        terminal.draw_with_blend(&text_buffer, *blend_mode);
+    }
+
+    assert_eq!(
+        renderer::blend_factors(BlendMode::Alpha),
+        (gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA)
+    );
+    assert_eq!(
+        renderer::blend_factors(BlendMode::Additive),
+        (gl::SRC_ALPHA, gl::ONE)
+    );
+    assert_eq!(
+        renderer::blend_factors(BlendMode::Multiply),
+        (gl::DST_COLOR, gl::ZERO)
+    );
+}
+
+#[test]
+fn needs_redraw() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((4, 4));
+    terminal.flush(&mut text_buffer);
+    assert!(terminal.needs_redraw());
+    terminal.draw(&text_buffer);
+    assert!(!terminal.needs_redraw());
+
+    terminal.flush(&mut text_buffer);
+    assert!(!terminal.needs_redraw());
+
+    text_buffer.write("abc");
+    terminal.flush(&mut text_buffer);
+    assert!(terminal.needs_redraw());
+    terminal.draw(&text_buffer);
+    assert!(!terminal.needs_redraw());
+
+    terminal.request_redraw();
+    assert!(terminal.needs_redraw());
+}
+
+#[test]
+fn min_gl_version() {
+    assert_eq!(TerminalBuilder::new().min_gl, (3, 3));
+    assert!(renderer::check_gl_version("3.3.0 Mesa 18.0.5", (3, 3)).is_ok());
+    assert!(renderer::check_gl_version("4.6.0 NVIDIA 390.77", (3, 3)).is_ok());
+    assert!(renderer::check_gl_version("3.0 Mesa 18.0.5", (3, 3)).is_err());
+    assert!(renderer::check_gl_version("3.0 Mesa 18.0.5", (3, 0)).is_ok());
+
+    let builder = TerminalBuilder::new().with_min_gl(99, 0);
+    assert_eq!(builder.min_gl, (99, 0));
+    assert_eq!(
+        renderer::check_gl_version("4.6.0 NVIDIA 390.77", builder.min_gl),
+        Err("GL version too low: OpenGL 4.6.0 NVIDIA 390.77, required 99.0".to_owned())
+    );
+    // Headless terminals have no GL context, so the version is not checked
+    assert!(test_setup_builder().with_min_gl(99, 0).try_build().is_ok());
+
+    // Unless the version is given
+    renderer::set_version_override(Some("4.6.0 NVIDIA 390.77"));
+    let too_low = test_setup_builder().with_min_gl(99, 0).try_build();
+    let high_enough = test_setup_builder().with_min_gl(4, 5).try_build();
+    renderer::set_version_override(None);
+    assert_eq!(
+        too_low.err(),
+        Some("GL version too low: OpenGL 4.6.0 NVIDIA 390.77, required 99.0".to_owned())
+    );
+    assert!(high_enough.is_ok());
+}
+
+#[test]
+fn invalid_gl_version() {
+    assert!(renderer::check_gl_version("", (3, 3)).is_err());
+    assert!(renderer::check_gl_version("OpenGL ES", (3, 3)).is_err());
+    assert!(renderer::check_gl_version("4", (3, 3)).is_err());
+    assert_eq!(
+        renderer::check_gl_version("not a version", (3, 3)),
+        Err("Invalid version value from GL driver: 'not a version'".to_owned())
+    );
+}
+
+#[test]
+fn set_vsync() {
+    let mut terminal = test_setup_open_terminal();
+    if terminal.headless {
+        assert!(terminal.set_vsync(false));
+        assert!(terminal.set_vsync(true));
+    } else {
+        // Vsync is enabled by default, and can't be changed after opening the window
+        assert!(terminal.set_vsync(true));
+        assert!(!terminal.set_vsync(false));
+    }
+}
@@ -2,7 +2,8 @@ use super::{
     random_char, random_color, random_text, run_multiple_times, test_setup_text_buffer,
     test_setup_text_buffer_with_terminal,
 };
-use crate::TextStyle;
+use crate::renderer::{backgroundmesh, textbuffermesh};
+use crate::{Font, FontFormat, TermCharacter, TerminalBuilder, TextBuffer, TextStyle};
 use rand::{thread_rng, Rng};
 
 #[test]
@@ -121,3 +122,642 @@ fn cursor_move() {
         assert_eq!(text_buffer.get_cursor_position(), (width - 1, height - 1));
     });
 }
+
+#[test]
+fn create_matching() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((13, 7));
+    text_buffer.cursor.style.fg_color = random_color();
+    text_buffer.set_padding(1, 2, 3, 1);
+    text_buffer.set_proportional(true);
+    text_buffer.set_opaque_background(true);
+    text_buffer.set_time_scale(0.5);
+    text_buffer.set_time_offset(2.0);
+
+    let matching = TextBuffer::create_matching(&terminal, &text_buffer).unwrap();
+    assert_eq!(matching.get_dimensions(), (13, 7));
+    assert_eq!(matching.aspect_ratio, text_buffer.aspect_ratio);
+    assert_eq!(matching.cursor.style, text_buffer.cursor.style);
+    assert_eq!(matching.get_padding(), (1, 2, 3, 1));
+    assert_eq!(matching.get_cursor_position(), (1, 2));
+    assert!(matching.is_proportional());
+    assert!(matching.is_opaque_background());
+    assert_eq!(matching.get_time_scale(), 0.5);
+    assert_eq!(matching.get_time_offset(), 2.0);
+    assert_ne!(matching.get_idx(), text_buffer.get_idx());
+}
+
+#[test]
+fn diff() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((6, 4));
+    let other = TextBuffer::create_matching(&terminal, &text_buffer).unwrap();
+    assert_eq!(text_buffer.diff(&other), Ok(Vec::new()));
+
+    text_buffer.cursor.move_to(4, 2);
+    text_buffer.put_char('x');
+    let character = text_buffer.get_character(4, 2).unwrap();
+    assert_eq!(text_buffer.diff(&other), Ok(vec![(4, 2, character)]));
+
+    let smaller = TextBuffer::create(&terminal, (5, 4)).unwrap();
+    assert!(text_buffer.diff(&smaller).is_err());
+}
+
+#[test]
+fn bytes_round_trip() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((5, 3));
+    for _ in 0..8 {
+        let mut rnd = thread_rng();
+        text_buffer.cursor.style = TextStyle {
+            fg_color: random_color(),
+            bg_color: random_color(),
+            shakiness: rnd.gen(),
+        };
+        text_buffer
+            .cursor
+            .move_to(rnd.gen_range(0, 5), rnd.gen_range(0, 3));
+        text_buffer.put_char(random_char());
+    }
+
+    let bytes = text_buffer.to_bytes();
+    let decoded = TextBuffer::from_bytes(&terminal, &bytes).unwrap();
+    assert_eq!(decoded.get_dimensions(), (5, 3));
+    assert_eq!(decoded.diff(&text_buffer), Ok(Vec::new()));
+
+    assert!(TextBuffer::from_bytes(&terminal, &bytes[..bytes.len() - 1]).is_err());
+    assert!(TextBuffer::from_bytes(&terminal, b"nope").is_err());
+
+    // Crafted headers return errors instead of panicking
+    let header = |width: u32, height: u32| {
+        let mut header = bytes[..5].to_vec();
+        header.extend_from_slice(&width.to_le_bytes());
+        header.extend_from_slice(&height.to_le_bytes());
+        header
+    };
+    assert!(TextBuffer::from_bytes(&terminal, &header(0xFFFF_FFFF, 0xFFFF_FFFF)).is_err());
+    assert!(TextBuffer::from_bytes(&terminal, &header(0, 3)).is_err());
+    assert!(TextBuffer::from_bytes(&terminal, &header(5, 0)).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_text_style() {
+    let style = TextStyle {
+        fg_color: [1.0, 0.5, 0.25, 1.0],
+        bg_color: [0.0, 0.0, 0.0, 0.5],
+        shakiness: 0.75,
+    };
+    let json = serde_json::to_string(&style).unwrap();
+    assert_eq!(serde_json::from_str::<TextStyle>(&json).unwrap(), style);
+}
+
+#[test]
+fn tint_region() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((4, 4));
+    text_buffer.cursor.style.fg_color = [1.0, 0.5, 0.25, 1.0];
+    text_buffer.cursor.style.bg_color = [0.5, 0.5, 0.5, 1.0];
+    text_buffer.cursor.move_to(1, 1);
+    text_buffer.write("ab");
+    terminal.flush(&mut text_buffer);
+    assert!(!text_buffer.is_dirty());
+
+    text_buffer.tint_region((1, 1), (1, 5), [0.5, 0.5, 0.5, 1.0]);
+    assert!(text_buffer.is_dirty());
+
+    let tinted = text_buffer.get_character(1, 1).unwrap().style;
+    assert_eq!(tinted.fg_color, [0.5, 0.25, 0.125, 1.0]);
+    assert_eq!(tinted.bg_color, [0.25, 0.25, 0.25, 1.0]);
+
+    let untouched = text_buffer.get_character(2, 1).unwrap().style;
+    assert_eq!(untouched.fg_color, [1.0, 0.5, 0.25, 1.0]);
+
+    text_buffer.tint_region((0, 0), (4, 4), [4.0, 4.0, 4.0, 1.0]);
+    let clamped = text_buffer.get_character(2, 1).unwrap().style;
+    assert_eq!(clamped.fg_color, [1.0, 1.0, 1.0, 1.0]);
+
+    // Areas reaching past the end of u32 are cut to the TextBuffer as well
+    text_buffer.tint_region((3, 3), (u32::MAX, u32::MAX), [0.0; 4]);
+    assert_eq!(
+        text_buffer.get_character(3, 3).unwrap().style.bg_color,
+        [0.0; 4]
+    );
+}
+
+#[test]
+fn dirty_bounds() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((10, 8));
+    assert_eq!(text_buffer.dirty_bounds(), Some((0, 0, 9, 7)));
+
+    terminal.flush(&mut text_buffer);
+    assert_eq!(text_buffer.dirty_bounds(), None);
+
+    text_buffer.cursor.move_to(7, 1);
+    text_buffer.put_char('a');
+    text_buffer.cursor.move_to(2, 6);
+    text_buffer.put_char('b');
+    assert_eq!(text_buffer.dirty_bounds(), Some((2, 1, 7, 6)));
+
+    terminal.flush(&mut text_buffer);
+    assert_eq!(text_buffer.dirty_bounds(), None);
+}
+
+#[test]
+fn write_aligned() {
+    let mut text_buffer = test_setup_text_buffer((10, 3));
+    let style = TextStyle {
+        fg_color: random_color(),
+        ..Default::default()
+    };
+    text_buffer.cursor.style = style;
+
+    text_buffer.write_centered(0, "abcd");
+    assert_eq!(text_buffer.get_character(2, 0).unwrap().get_char(), ' ');
+    assert_eq!(text_buffer.get_character(3, 0).unwrap().get_char(), 'a');
+    assert_eq!(text_buffer.get_character(6, 0).unwrap().get_char(), 'd');
+    assert_eq!(text_buffer.get_character(3, 0).unwrap().style, style);
+
+    text_buffer.write_right_aligned(1, "abcd");
+    assert_eq!(text_buffer.get_character(6, 1).unwrap().get_char(), 'a');
+    assert_eq!(text_buffer.get_character(9, 1).unwrap().get_char(), 'd');
+
+    // Overlong text is cut off
+    text_buffer.write_centered(2, "0123456789abc");
+    assert_eq!(text_buffer.get_character(0, 2).unwrap().get_char(), '0');
+    assert_eq!(text_buffer.get_character(9, 2).unwrap().get_char(), '9');
+    assert_eq!(text_buffer.get_character(0, 0).unwrap().get_char(), ' ');
+}
+
+#[test]
+fn draw_line_joins() {
+    use crate::BorderChars;
+
+    let border_chars = BorderChars::default();
+    let mut text_buffer = test_setup_text_buffer((5, 5));
+
+    text_buffer
+        .draw_line((0, 2), (4, 2), &border_chars)
+        .unwrap();
+    text_buffer
+        .draw_line((2, 0), (2, 4), &border_chars)
+        .unwrap();
+
+    let char_at = |x, y| text_buffer.get_character(x, y).unwrap().get_char();
+    assert_eq!(char_at(2, 2), border_chars.middle_split);
+    assert_eq!(char_at(0, 2), border_chars.horizontal_line);
+    assert_eq!(char_at(4, 2), border_chars.horizontal_line);
+    assert_eq!(char_at(2, 0), border_chars.vertical_line);
+    assert_eq!(char_at(2, 4), border_chars.vertical_line);
+
+    // A line ending on another line creates a split and a corner
+    text_buffer
+        .draw_line((4, 0), (4, 2), &border_chars)
+        .unwrap();
+    let char_at = |x, y| text_buffer.get_character(x, y).unwrap().get_char();
+    assert_eq!(char_at(4, 2), border_chars.bottom_right);
+    text_buffer
+        .draw_line((0, 4), (2, 4), &border_chars)
+        .unwrap();
+    let char_at = |x, y| text_buffer.get_character(x, y).unwrap().get_char();
+    assert_eq!(char_at(2, 4), border_chars.bottom_right);
+    text_buffer
+        .draw_line((2, 4), (4, 4), &border_chars)
+        .unwrap();
+    let char_at = |x, y| text_buffer.get_character(x, y).unwrap().get_char();
+    assert_eq!(char_at(2, 4), border_chars.bottom_split);
+
+    assert!(text_buffer
+        .draw_line((0, 0), (1, 1), &border_chars)
+        .is_err());
+    assert!(text_buffer
+        .draw_line((0, 0), (5, 0), &border_chars)
+        .is_err());
+}
+
+#[test]
+fn time_scale_and_offset() {
+    let mut text_buffer = test_setup_text_buffer((2, 2));
+    assert_eq!(text_buffer.calc_time(3.0), 3.0);
+
+    text_buffer.set_time_scale(0.5);
+    text_buffer.set_time_offset(1.0);
+    assert_eq!(text_buffer.calc_time(3.0), 2.5);
+
+    // A time scale of zero freezes the time
+    text_buffer.set_time_scale(0.0);
+    assert_eq!(text_buffer.calc_time(3.0), 1.0);
+    assert_eq!(text_buffer.calc_time(100.0), 1.0);
+}
+
+#[test]
+fn replace_char() {
+    let mut text_buffer = test_setup_text_buffer((3, 3));
+    let style = TextStyle {
+        fg_color: random_color(),
+        bg_color: random_color(),
+        ..Default::default()
+    };
+    text_buffer.cursor.style = style;
+    text_buffer.cursor.move_to(1, 1);
+    text_buffer.put_char('a');
+    let original = text_buffer.get_character(1, 1).unwrap();
+
+    text_buffer.cursor.style = Default::default();
+    text_buffer.cursor.move_to(0, 0);
+    text_buffer.put_char('b');
+    let replacement = text_buffer.get_character(0, 0).unwrap();
+
+    let previous = text_buffer.replace_char(1, 1, replacement).unwrap();
+    assert_eq!(previous, original);
+    assert_eq!(previous.get_char(), 'a');
+    assert_eq!(previous.style, style);
+    assert_eq!(text_buffer.get_character(1, 1), Some(replacement));
+
+    // Undo
+    text_buffer.replace_char(1, 1, previous);
+    assert_eq!(text_buffer.get_character(1, 1), Some(original));
+
+    assert_eq!(text_buffer.replace_char(3, 0, replacement), None);
+}
+
+#[test]
+fn write_cells() {
+    let (width, height) = (4, 3);
+    let mut text_buffer = test_setup_text_buffer((width, height));
+
+    let cells: Vec<TermCharacter> = (0..width * height)
+        .map(|_| {
+            let style = TextStyle {
+                fg_color: random_color(),
+                bg_color: random_color(),
+                ..Default::default()
+            };
+            TermCharacter::new(random_char() as u16, style)
+        })
+        .collect();
+    assert_eq!(text_buffer.write_cells(&cells), Ok(()));
+
+    for y in 0..height {
+        for x in 0..width {
+            assert_eq!(
+                text_buffer.get_character(x, y),
+                Some(cells[(y * width + x) as usize])
+            );
+        }
+    }
+    assert_eq!(
+        text_buffer.dirty_bounds(),
+        Some((0, 0, width - 1, height - 1))
+    );
+
+    assert!(text_buffer.write_cells(&cells[1..]).is_err());
+}
+
+#[test]
+fn proportional() {
+    // A font with a narrow 'i' and a wide 'm', both without offsets
+    let font = Font::load_raw(
+        &FontFormat::SFL,
+        "\"Test\"\n44 77\nimage.png\n3\n105 0 0 10 30 0 0 20\n109 0 0 40 30 0 0 50\n63 0 0 20 30 0 0 35\n0\n",
+        &include_bytes!("../../fonts/source_code_pro.png")[..],
+    );
+    let mut text_buffer = test_setup_text_buffer((3, 1));
+    text_buffer.write("imm");
+
+    // Returns the left edges of the glyphs
+    let glyph_xs = |text_buffer: &TextBuffer| -> Vec<f32> {
+        let buffers = textbuffermesh::create_vertex_buffers(text_buffer, &font);
+        buffers.pos.chunks(12).map(|quad| quad[0]).collect()
+    };
+
+    assert!(!text_buffer.is_proportional());
+    let xs = glyph_xs(&text_buffer);
+    assert!(((xs[1] - xs[0]) - (xs[2] - xs[1])).abs() < 0.0001);
+
+    text_buffer.set_proportional(true);
+    assert!(text_buffer.is_proportional());
+    let xs = glyph_xs(&text_buffer);
+    assert_eq!(xs[0], 0.0);
+    let advance_i = xs[1] - xs[0];
+    let advance_m = xs[2] - xs[1];
+    assert!((advance_m / advance_i - 2.5).abs() < 0.0001);
+}
+
+#[test]
+fn wide_characters() {
+    // A font where '世' is twice as wide as the other characters
+    let font = Font::load_raw(
+        &FontFormat::SFL,
+        "\"Test\"\n44 77\nimage.png\n4\n32 0 0 0 0 0 0 37\n97 0 0 20 30 0 0 37\n63 0 0 20 30 0 0 37\n19990 0 0 60 30 0 0 74\n0\n",
+        &include_bytes!("../../fonts/source_code_pro.png")[..],
+    );
+    let terminal = TerminalBuilder::new()
+        .with_headless(true)
+        .with_font(font)
+        .build();
+    let mut text_buffer = TextBuffer::create(&terminal, (4, 2)).unwrap();
+    text_buffer.put_char('x');
+    text_buffer.cursor.move_to(0, 0);
+
+    text_buffer.write("世a");
+    assert_eq!(text_buffer.get_character(0, 0).unwrap().get_char(), '世');
+    assert_eq!(text_buffer.get_character(1, 0).unwrap().get_char(), ' ');
+    assert_eq!(text_buffer.get_character(2, 0).unwrap().get_char(), 'a');
+    assert_eq!(text_buffer.get_cursor_position(), (3, 0));
+
+    // No room for the second cell at the end of the row
+    text_buffer.put_char('世');
+    assert_eq!(text_buffer.get_character(3, 0).unwrap().get_char(), '世');
+    assert_eq!(text_buffer.get_cursor_position(), (0, 1));
+}
+
+#[test]
+fn save_and_restore_cursor() {
+    let mut text_buffer = test_setup_text_buffer((8, 8));
+    let style = TextStyle {
+        fg_color: random_color(),
+        bg_color: random_color(),
+        shakiness: 0.5,
+    };
+    text_buffer.cursor.move_to(2, 3);
+    text_buffer.cursor.style = style;
+    text_buffer.save_cursor();
+
+    text_buffer.cursor.move_to(5, 5);
+    text_buffer.cursor.style = Default::default();
+    text_buffer.cursor.set_limits(Some(4), None, Some(4), None);
+    text_buffer.save_cursor();
+    text_buffer.write("ab");
+    assert!(text_buffer.restore_cursor());
+    assert_eq!(text_buffer.get_cursor_position(), (5, 5));
+
+    assert!(text_buffer.restore_cursor());
+    assert_eq!(text_buffer.get_cursor_position(), (2, 3));
+    assert_eq!(text_buffer.cursor.style, style);
+    assert_eq!(text_buffer.cursor.get_limits().get_min_x(), 0);
+
+    assert!(!text_buffer.restore_cursor());
+    assert_eq!(text_buffer.get_cursor_position(), (2, 3));
+}
+
+#[test]
+fn opaque_background() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((5, 4));
+    text_buffer.cursor.style.bg_color = [1.0, 0.0, 0.0, 1.0];
+    text_buffer.write("ab");
+
+    let (pos, col) = backgroundmesh::create_vertex_buffers(&text_buffer);
+    assert_eq!(pos.len(), 2 * 12);
+    assert_eq!(col.len(), 2 * 24);
+
+    assert!(!text_buffer.is_opaque_background());
+    text_buffer.set_opaque_background(true);
+    assert!(text_buffer.is_opaque_background());
+    let (pos, col) = backgroundmesh::create_vertex_buffers(&text_buffer);
+    assert_eq!(pos.len(), 5 * 4 * 12);
+    assert_eq!(col.len(), 5 * 4 * 24);
+    assert_eq!(&col[..4], &[1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(&col[col.len() - 4..], &terminal.clear_color);
+}
+
+#[test]
+fn set_bg_and_fg() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((3, 3));
+    let style = TextStyle {
+        fg_color: random_color(),
+        bg_color: random_color(),
+        shakiness: 0.5,
+    };
+    text_buffer.cursor.style = style;
+    text_buffer.cursor.move_to(1, 1);
+    text_buffer.put_char('a');
+    terminal.flush(&mut text_buffer);
+
+    let bg_color = random_color();
+    text_buffer.set_bg(1, 1, bg_color);
+    let character = text_buffer.get_character(1, 1).unwrap();
+    assert_eq!(character.get_char(), 'a');
+    assert_eq!(character.style.bg_color, bg_color);
+    assert_eq!(character.style.fg_color, style.fg_color);
+    assert_eq!(character.style.shakiness, 0.5);
+    assert_eq!(text_buffer.dirty_bounds(), Some((1, 1, 1, 1)));
+
+    let fg_color = random_color();
+    text_buffer.set_fg(1, 1, fg_color);
+    let character = text_buffer.get_character(1, 1).unwrap();
+    assert_eq!(character.get_char(), 'a');
+    assert_eq!(character.style.fg_color, fg_color);
+    assert_eq!(character.style.bg_color, bg_color);
+
+    // Out of bounds does nothing
+    terminal.flush(&mut text_buffer);
+    text_buffer.set_bg(3, 0, bg_color);
+    text_buffer.set_fg(0, 3, fg_color);
+    assert_eq!(text_buffer.dirty_bounds(), None);
+}
+
+#[test]
+fn fill_pattern() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((4, 4));
+    let a = TermCharacter::new('#' as u16, TextStyle::inverted());
+    let b = TermCharacter::new('.' as u16, Default::default());
+    terminal.flush(&mut text_buffer);
+
+    text_buffer.fill_pattern((1, 1), (2, 2), a, b);
+    assert_eq!(text_buffer.get_character(1, 1), Some(a));
+    assert_eq!(text_buffer.get_character(2, 1), Some(b));
+    assert_eq!(text_buffer.get_character(1, 2), Some(b));
+    assert_eq!(text_buffer.get_character(2, 2), Some(a));
+    assert_eq!(text_buffer.get_character(0, 0).unwrap().get_char(), ' ');
+    assert_eq!(text_buffer.get_character(3, 1).unwrap().get_char(), ' ');
+    assert_eq!(text_buffer.dirty_bounds(), Some((1, 1, 2, 2)));
+
+    // Outside of the TextBuffer is ignored
+    text_buffer.fill_pattern((3, 3), (5, 5), b, a);
+    assert_eq!(text_buffer.get_character(3, 3), Some(b));
+    text_buffer.fill_pattern((3, 0), (u32::MAX, u32::MAX), a, b);
+    assert_eq!(text_buffer.get_character(3, 0), Some(a));
+    text_buffer.fill_pattern((u32::MAX, 0), (1, 1), a, b);
+}
+
+#[test]
+fn shift_row() {
+    let mut text_buffer = test_setup_text_buffer((5, 2));
+    let row = |text_buffer: &crate::TextBuffer, y: u32| -> String {
+        (0..5)
+            .map(|x| text_buffer.get_character(x, y).unwrap().get_char())
+            .collect()
+    };
+    text_buffer.write("abcdefghij");
+
+    text_buffer.shift_row(0, 2, true);
+    assert_eq!(row(&text_buffer, 0), "deabc");
+    text_buffer.shift_row(0, -3, true);
+    assert_eq!(row(&text_buffer, 0), "bcdea");
+    text_buffer.shift_row(0, 7, true);
+    assert_eq!(row(&text_buffer, 0), "eabcd");
+
+    text_buffer.shift_row(1, 2, false);
+    assert_eq!(row(&text_buffer, 1), "  fgh");
+    text_buffer.shift_row(1, -3, false);
+    assert_eq!(row(&text_buffer, 1), "gh   ");
+    text_buffer.shift_row(1, 10, false);
+    assert_eq!(row(&text_buffer, 1), "     ");
+
+    // The other row is untouched
+    assert_eq!(row(&text_buffer, 0), "eabcd");
+}
+
+#[test]
+fn shift_column() {
+    let mut text_buffer = test_setup_text_buffer((2, 4));
+    let column = |text_buffer: &crate::TextBuffer, x: u32| -> String {
+        (0..4)
+            .map(|y| text_buffer.get_character(x, y).unwrap().get_char())
+            .collect()
+    };
+    text_buffer.write("aebfcgdh");
+
+    text_buffer.shift_column(0, 1, true);
+    assert_eq!(column(&text_buffer, 0), "dabc");
+    text_buffer.shift_column(1, -1, false);
+    assert_eq!(column(&text_buffer, 1), "fgh ");
+}
+
+#[test]
+fn map_style() {
+    let mut text_buffer = test_setup_text_buffer((4, 2));
+    text_buffer.cursor.style.fg_color = random_color();
+    text_buffer.cursor.style.shakiness = 0.5;
+    text_buffer.write("abc");
+    text_buffer.cursor.style.fg_color = random_color();
+    text_buffer.write("def");
+
+    let color = [0.25, 0.5, 0.75, 1.0];
+    text_buffer.map_style(|style| TextStyle {
+        fg_color: color,
+        ..style
+    });
+
+    let text: String = "abcdef  ".into();
+    for (idx, c) in text.chars().enumerate() {
+        let character = text_buffer
+            .get_character(idx as u32 % 4, idx as u32 / 4)
+            .unwrap();
+        assert_eq!(character.get_char(), c);
+        assert_eq!(character.style.fg_color, color);
+        if c != ' ' {
+            assert_eq!(character.style.shakiness, 0.5);
+        }
+    }
+    assert!(text_buffer.is_dirty());
+}
+
+#[test]
+fn width_and_height() {
+    let text_buffer = test_setup_text_buffer((7, 3));
+    assert_eq!(
+        (text_buffer.width(), text_buffer.height()),
+        text_buffer.get_dimensions()
+    );
+    assert_eq!(text_buffer.width(), 7);
+    assert_eq!(text_buffer.height(), 3);
+}
+
+#[test]
+fn term_character_visibility() {
+    let space = TermCharacter::new(' ' as u16, Default::default());
+    let letter = TermCharacter::new('a' as u16, Default::default());
+    assert!(space.is_space());
+    assert!(!letter.is_space());
+
+    let style = |bg_color| TextStyle {
+        bg_color,
+        ..Default::default()
+    };
+    let transparent = TermCharacter::new(' ' as u16, style([1.0, 0.0, 0.0, 0.0]));
+    let opaque = TermCharacter::new(' ' as u16, style([1.0, 0.0, 0.0, 0.5]));
+    assert!(!transparent.has_visible_background());
+    assert!(opaque.has_visible_background());
+}
+
+#[test]
+fn put_raw_char_at() {
+    let (mut text_buffer, terminal) = test_setup_text_buffer_with_terminal((4, 3));
+    terminal.flush(&mut text_buffer);
+    text_buffer.cursor.move_to(1, 1);
+
+    let style = TextStyle::inverted();
+    text_buffer.put_raw_char_at(2, 1, '#' as u16, style);
+    let character = text_buffer.get_character(2, 1).unwrap();
+    assert_eq!(character.get_char(), '#');
+    assert_eq!(character.style, style);
+    assert_eq!(text_buffer.get_cursor_position(), (1, 1));
+    assert_eq!(text_buffer.dirty_bounds(), Some((2, 1, 2, 1)));
+
+    // Out of bounds is ignored
+    terminal.flush(&mut text_buffer);
+    text_buffer.put_raw_char_at(4, 0, '#' as u16, style);
+    text_buffer.put_raw_char_at(0, 3, '#' as u16, style);
+    assert!(!text_buffer.is_dirty());
+}
+
+#[test]
+fn try_set_char() {
+    let mut text_buffer = test_setup_text_buffer((4, 3));
+    let character = TermCharacter::new('#' as u16, TextStyle::inverted());
+
+    assert!(text_buffer.try_set_char(3, 2, character).is_ok());
+    assert_eq!(text_buffer.get_character(3, 2), Some(character));
+
+    // Positions that would still be inside the character list must not wrap to the next row
+    assert!(text_buffer.try_set_char(4, 0, character).is_err());
+    assert!(text_buffer.try_set_char(0, 3, character).is_err());
+    assert_ne!(text_buffer.get_character(0, 1), Some(character));
+}
+
+#[test]
+fn padding() {
+    let mut text_buffer = test_setup_text_buffer((6, 5));
+    text_buffer.set_padding(1, 2, 1, 1);
+    assert_eq!(text_buffer.get_padding(), (1, 2, 1, 1));
+
+    text_buffer.cursor.move_to(0, 0);
+    assert_eq!(text_buffer.get_cursor_position(), (1, 2));
+    text_buffer.cursor.move_to(10, 10);
+    assert_eq!(text_buffer.get_cursor_position(), (4, 3));
+
+    // Limits can not reach into the padding
+    text_buffer.cursor.set_limits(Some(0), None, None, None);
+    text_buffer.cursor.move_to(0, 0);
+    assert_eq!(text_buffer.get_cursor_position(), (1, 2));
+
+    // Only the area inside the padding is cleared
+    for y in 0..5 {
+        for x in 0..6 {
+            text_buffer.set_char(x, y, TermCharacter::new('#' as u16, Default::default()));
+        }
+    }
+    text_buffer.clear();
+    let is_cleared = |x, y| text_buffer.get_character(x, y).unwrap().get_char() == ' ';
+    assert!(is_cleared(1, 2));
+    assert!(is_cleared(4, 3));
+    assert!(!is_cleared(0, 2));
+    assert!(!is_cleared(1, 1));
+    assert!(!is_cleared(5, 3));
+    assert!(!is_cleared(4, 4));
+}
+
+#[test]
+fn padding_survives_restore_cursor() {
+    let mut text_buffer = test_setup_text_buffer((6, 5));
+    text_buffer.save_cursor();
+    text_buffer.set_padding(1, 2, 1, 1);
+    assert!(text_buffer.restore_cursor());
+
+    assert_eq!(text_buffer.get_padding(), (1, 2, 1, 1));
+    assert_eq!(text_buffer.get_cursor_position(), (1, 2));
+    text_buffer.cursor.move_to(0, 0);
+    assert_eq!(text_buffer.get_cursor_position(), (1, 2));
+    // Padding larger than the TextBuffer does not overflow
+    text_buffer.set_padding(0, 0, u32::MAX, u32::MAX);
+    assert_eq!(text_buffer.get_cursor_position(), (0, 0));
+    text_buffer.clear();
+}
@@ -0,0 +1,107 @@
+use crate::text_processing::{
+    default_style, split_lines, strip, test_process, Chain, DefaultProcessor, LineProcessor,
+    OptTextStyle, Processable, ProcessedChar, TextProcessor,
+};
+use crate::{Parser, TextStyle};
+
+/// Turns all text into uppercase
+struct Uppercase;
+
+impl TextProcessor for Uppercase {
+    fn process(&self, processables: Vec<Processable>) -> Vec<ProcessedChar> {
+        let mut list = DefaultProcessor.process(processables);
+        for c in &mut list {
+            c.character = c.character.to_ascii_uppercase();
+        }
+        list
+    }
+}
+
+/// Doubles every character
+struct Double;
+
+impl TextProcessor for Double {
+    fn process(&self, processables: Vec<Processable>) -> Vec<ProcessedChar> {
+        DefaultProcessor
+            .process(processables)
+            .into_iter()
+            .flat_map(|c| vec![c.clone(), c])
+            .collect()
+    }
+}
+
+#[test]
+fn chain() {
+    let chain = Chain(vec![Box::new(Uppercase), Box::new(Double)]);
+    assert_eq!(
+        strip(&chain.process(vec!["ab".into(), "c".into()])),
+        "AABBCC"
+    );
+
+    let chain = Chain(Vec::new());
+    assert_eq!(strip(&chain.process(vec!["ab".into()])), "ab");
+}
+
+#[test]
+fn chain_keeps_styles() {
+    let mut parser = Parser::new();
+    parser.add_color("red", [1.0, 0.0, 0.0, 1.0]);
+    let chain = Chain(vec![Box::new(parser), Box::new(Uppercase)]);
+
+    let processed = chain.process(vec!["a[fg=red]b[/fg]".into()]);
+    assert_eq!(strip(&processed), "AB");
+    assert_eq!(processed[0].style.fg_color, None);
+    assert_eq!(processed[1].style.fg_color, Some([1.0, 0.0, 0.0, 1.0]));
+}
+
+#[test]
+fn strip_and_default_style() {
+    let mut parser = Parser::new();
+    parser.add_color("red", [1.0, 0.0, 0.0, 1.0]);
+    let processed = parser.parse("plain [fg=red][bg=red][shake=0.5]styled[/shake] text");
+    assert_eq!(strip(&processed), "plain styled text");
+
+    let defaulted = default_style(&processed);
+    assert_eq!(strip(&defaulted), "plain styled text");
+    assert!(processed[6].style.fg_color.is_some());
+    for c in defaulted {
+        assert_eq!(c.style.fg_color, None);
+        assert_eq!(c.style.bg_color, None);
+        assert_eq!(c.style.shakiness, None);
+    }
+}
+
+#[test]
+fn test_process_default_processor() {
+    let processed = test_process(&DefaultProcessor, "a\nb");
+    assert_eq!(
+        processed,
+        vec![
+            ProcessedChar::new('a', OptTextStyle::default()),
+            ProcessedChar::new('\n', OptTextStyle::default()),
+            ProcessedChar::new('b', OptTextStyle::default()),
+        ]
+    );
+
+    let style = OptTextStyle {
+        fg_color: Some([1.0, 0.0, 0.0, 1.0]),
+        ..Default::default()
+    };
+    let applied = style.apply_to(TextStyle::inverted());
+    assert_eq!(applied.fg_color, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(applied.bg_color, TextStyle::inverted().bg_color);
+}
+
+#[test]
+fn line_processor() {
+    let processed = test_process(&LineProcessor, "one\ntwo\r\n\rthree");
+    assert_eq!(strip(&processed), "one\ntwo\n\nthree");
+
+    let rows = split_lines(&processed);
+    let rows: Vec<String> = rows.iter().map(|row| strip(row)).collect();
+    assert_eq!(rows, vec!["one", "two", "", "three"]);
+
+    let rows = LineProcessor.process_lines(vec!["single".into()]);
+    assert_eq!(rows.len(), 1);
+    assert_eq!(strip(&rows[0]), "single");
+}
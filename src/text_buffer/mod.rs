@@ -9,6 +9,7 @@ use crate::renderer::textbuffermesh::TextBufferMesh;
 use crate::terminal::Terminal;
 use crate::text_processing::ProcessedChar;
 
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Represents a color with values from 0.0 to 1.0 (red, green, blue, alpha)
@@ -19,6 +20,11 @@ pub type RawCharacter = u16;
 
 static INDEX_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
+static BYTES_MAGIC: &[u8; 4] = b"GLTB";
+const BYTES_VERSION: u8 = 1;
+const BYTES_HEADER_LEN: usize = 13;
+const BYTES_CHARACTER_LEN: usize = 38;
+
 /// The `TextBuffer` acts as a "state machine" where you can set foreground color, background color and shakiness for the cursor,
 /// move the cursor around, clear the screen and write with the cursor (using the cursor's styles) (through [`TermCursor`](struct.TermCursor.html)),
 /// put, get characters or write strings.  
@@ -139,6 +145,16 @@ pub struct TextBuffer {
     pub cursor: TermCursor,
 
     dirty: bool,
+    dirty_bounds: Option<(u32, u32, u32, u32)>,
+
+    time_scale: f32,
+    time_offset: f32,
+
+    pub(crate) proportional: bool,
+    wide_chars: HashSet<RawCharacter>,
+    cursor_stack: Vec<TermCursor>,
+    pub(crate) opaque_background: bool,
+    pub(crate) clear_color: Color,
 }
 
 impl TextBuffer {
@@ -191,14 +207,106 @@ impl TextBuffer {
             aspect_ratio: true_width as f32 / true_height as f32,
 
             dirty: true,
+            dirty_bounds: Some((0, 0, width - 1, height - 1)),
+
+            time_scale: 1.0,
+            time_offset: 0.0,
+
+            proportional: false,
+            wide_chars: terminal.font.wide_characters(),
+            cursor_stack: Vec::new(),
+            opaque_background: false,
+            clear_color: terminal.clear_color,
         })
     }
 
-    pub(crate) fn get_idx(&self) -> u32 {
+    /// Creates a new empty text buffer with the same dimensions and settings as `other`.
+    ///
+    /// The cursor style, padding, proportional positioning, opaque background, time scale and time offset are copied.
+    /// Useful for creating layers of text buffers that are drawn on top of eachother.
+    pub fn create_matching(terminal: &Terminal, other: &TextBuffer) -> Result<TextBuffer, String> {
+        let mut text_buffer = TextBuffer::create(terminal, other.get_dimensions())?;
+        text_buffer.cursor.style = other.cursor.style;
+        let (left, top, right, bottom) = other.get_padding();
+        text_buffer.set_padding(left, top, right, bottom);
+        text_buffer.proportional = other.proportional;
+        text_buffer.opaque_background = other.opaque_background;
+        text_buffer.time_scale = other.time_scale;
+        text_buffer.time_offset = other.time_offset;
+        Ok(text_buffer)
+    }
+
+    /// Returns the unique index of this TextBuffer, see [`Cursor::get_location_for_idx`](struct.Cursor.html#method.get_location_for_idx).
+    pub fn get_idx(&self) -> u32 {
         self.index
     }
 
-    pub(crate) fn swap_buffers(&mut self, font: &Font) {
+    /// Sets how fast time passes for the shaking of this TextBuffer, relative to the Terminal. Default is 1.0.
+    ///
+    /// Setting the time scale to 0.0 freezes the shaking, useful for example when a menu is paused.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale;
+    }
+
+    /// Sets the time (in seconds) that is added to the time used for the shaking of this TextBuffer. Default is 0.0.
+    ///
+    /// The time used is `elapsed * time_scale + time_offset`.
+    pub fn set_time_offset(&mut self, time_offset: f32) {
+        self.time_offset = time_offset;
+    }
+
+    /// Returns the time scale of this TextBuffer, see [`set_time_scale`](#method.set_time_scale).
+    pub fn get_time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Returns the time offset of this TextBuffer, see [`set_time_offset`](#method.set_time_offset).
+    pub fn get_time_offset(&self) -> f32 {
+        self.time_offset
+    }
+
+    /// Sets whether the characters are positioned proportionally, using the real horizontal advance of each glyph
+    /// instead of a fixed width per cell. Useful for non-monospace fonts. Default is false.
+    ///
+    /// **Note:** This only affects how the text is drawn: the characters are still written, and the backgrounds drawn,
+    /// in a grid, so with proportional positioning the text may not line up with its background or with the grid.
+    pub fn set_proportional(&mut self, proportional: bool) {
+        if self.proportional != proportional {
+            self.proportional = proportional;
+            let (width, height) = self.get_dimensions();
+            self.mark_dirty((0, 0), (width - 1, height - 1));
+        }
+    }
+
+    /// Sets whether the background is drawn for every cell, even where the background color is fully transparent (`[0.0; 4]`, the default).
+    /// Those cells are then drawn with the clear color of the `Terminal`. Default is false.
+    ///
+    /// Useful for covering everything drawn before this TextBuffer with an opaque field.
+    pub fn set_opaque_background(&mut self, opaque_background: bool) {
+        if self.opaque_background != opaque_background {
+            self.opaque_background = opaque_background;
+            let (width, height) = self.get_dimensions();
+            self.mark_dirty((0, 0), (width - 1, height - 1));
+        }
+    }
+
+    /// Returns whether the background is drawn for every cell, see [`set_opaque_background`](#method.set_opaque_background).
+    pub fn is_opaque_background(&self) -> bool {
+        self.opaque_background
+    }
+
+    /// Returns whether the characters are positioned proportionally, see [`set_proportional`](#method.set_proportional).
+    pub fn is_proportional(&self) -> bool {
+        self.proportional
+    }
+
+    /// Returns the time used for shaking when drawing this TextBuffer, with `elapsed` being the time since the Terminal started.
+    pub(crate) fn calc_time(&self, elapsed: f32) -> f32 {
+        elapsed * self.time_scale + self.time_offset
+    }
+
+    /// Updates the meshes if the TextBuffer has changed, returns whether it had
+    pub(crate) fn swap_buffers(&mut self, font: &Font) -> bool {
         if self.dirty {
             if let (&Some(ref mesh), &Some(ref background_mesh)) =
                 (&self.mesh, &self.background_mesh)
@@ -207,6 +315,10 @@ impl TextBuffer {
                 background_mesh.update(&self);
             }
             self.dirty = false;
+            self.dirty_bounds = None;
+            true
+        } else {
+            false
         }
     }
 
@@ -215,9 +327,97 @@ impl TextBuffer {
         (self.width, self.height)
     }
 
+    /// Get the width of the text buffer (in characters)
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the height of the text buffer (in characters)
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
     /// Sets the character at the specified position. It is the user's responsibility to check if such a position exists.
+    ///
+    /// Panics if the position is out of bounds, see [`try_set_char`](#method.try_set_char) for a non-panicking version.
     pub fn set_char(&mut self, x: u32, y: u32, character: TermCharacter) {
+        self.try_set_char(x, y, character)
+            .expect("Failed to set character");
+    }
+
+    /// Sets the character at the specified position, or returns an error if the position is out of bounds.
+    pub fn try_set_char(&mut self, x: u32, y: u32, character: TermCharacter) -> Result<(), String> {
+        if x >= self.width || y >= self.height {
+            return Err(format!(
+                "Position ({}, {}) is out of bounds for a TextBuffer of size ({}, {})",
+                x, y, self.width, self.height
+            ));
+        }
         self.chars[(y * self.width + x) as usize] = character;
+        Ok(())
+    }
+
+    /// Copies the given characters into the whole `TextBuffer` in row-major order, marking the whole buffer dirty.
+    ///
+    /// Returns an error if the length of `cells` is not `width * height`. This is faster than calling `set_char` for every character.
+    pub fn write_cells(&mut self, cells: &[TermCharacter]) -> Result<(), String> {
+        if cells.len() != self.chars.len() {
+            return Err(format!(
+                "Expected {} characters ({}x{}), got {}",
+                self.chars.len(),
+                self.width,
+                self.height,
+                cells.len()
+            ));
+        }
+        self.chars.copy_from_slice(cells);
+        self.mark_dirty((0, 0), (self.width - 1, self.height - 1));
+        Ok(())
+    }
+
+    /// Replaces the style of every character with the style returned by `f`, leaving the characters themselves untouched.
+    ///
+    /// Useful for recoloring the whole `TextBuffer`, for example for a different color theme.
+    pub fn map_style<F: Fn(TextStyle) -> TextStyle>(&mut self, f: F) {
+        for character in &mut self.chars {
+            character.style = f(character.style);
+        }
+        self.mark_dirty((0, 0), (self.width - 1, self.height - 1));
+    }
+
+    /// Sets the background color of the character at the specified position, leaving the character and the rest of its style untouched.
+    ///
+    /// Does nothing if x/y are out of bounds.
+    pub fn set_bg(&mut self, x: u32, y: u32, color: Color) {
+        self.modify_style(x, y, |style| style.bg_color = color);
+    }
+
+    /// Sets the foreground color of the character at the specified position, leaving the character and the rest of its style untouched.
+    ///
+    /// Does nothing if x/y are out of bounds.
+    pub fn set_fg(&mut self, x: u32, y: u32, color: Color) {
+        self.modify_style(x, y, |style| style.fg_color = color);
+    }
+
+    /// Sets the character at the specified position and returns the character that was there before.
+    ///
+    /// Returns None and does nothing if x/y are out of bounds. Useful for example for keeping an undo history.
+    pub fn replace_char(
+        &mut self,
+        x: u32,
+        y: u32,
+        character: TermCharacter,
+    ) -> Option<TermCharacter> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let idx = (y * self.width + x) as usize;
+        let previous = self.chars[idx];
+        if previous != character {
+            self.chars[idx] = character;
+            self.mark_dirty((x, y), (x, y));
+        }
+        Some(previous)
     }
 
     /// Gets the TermChaacter in the given position
@@ -231,12 +431,296 @@ impl TextBuffer {
         }
     }
 
+    /// Encodes the dimensions and characters of this TextBuffer into bytes, which can be decoded with [`from_bytes`](#method.from_bytes).
+    ///
+    /// The format starts with a header of `GLTB`, a version byte and the width and height, followed by every character
+    /// and its style. All numbers are little-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(BYTES_HEADER_LEN + self.chars.len() * BYTES_CHARACTER_LEN);
+        bytes.extend_from_slice(BYTES_MAGIC);
+        bytes.push(BYTES_VERSION);
+        bytes.extend_from_slice(&self.width.to_le_bytes());
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+        for character in &self.chars {
+            bytes.extend_from_slice(&character.character.to_le_bytes());
+            let style = character.style;
+            for value in style.fg_color.iter().chain(style.bg_color.iter()) {
+                bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+            bytes.extend_from_slice(&style.shakiness.to_bits().to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Creates a new TextBuffer from bytes encoded with [`to_bytes`](#method.to_bytes).
+    pub fn from_bytes(terminal: &Terminal, bytes: &[u8]) -> Result<TextBuffer, String> {
+        if bytes.len() < BYTES_HEADER_LEN || &bytes[0..4] != BYTES_MAGIC {
+            return Err("Bytes are not an encoded TextBuffer".to_owned());
+        }
+        if bytes[4] != BYTES_VERSION {
+            return Err(format!(
+                "Unsupported TextBuffer encoding version {}",
+                bytes[4]
+            ));
+        }
+        let read_u32 = |idx: usize| {
+            let mut value = [0; 4];
+            value.copy_from_slice(&bytes[idx..idx + 4]);
+            u32::from_le_bytes(value)
+        };
+        let (width, height) = (read_u32(5), read_u32(9));
+        if width == 0 || height == 0 {
+            return Err(format!(
+                "Encoded TextBuffer has invalid dimensions ({}, {})",
+                width, height
+            ));
+        }
+
+        let expected_len = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|count| count.checked_mul(BYTES_CHARACTER_LEN))
+            .and_then(|len| len.checked_add(BYTES_HEADER_LEN))
+            .ok_or_else(|| {
+                format!(
+                    "Encoded TextBuffer dimensions ({}, {}) are too large",
+                    width, height
+                )
+            })?;
+        if bytes.len() != expected_len {
+            return Err(format!(
+                "Encoded TextBuffer has a wrong length; expected {} bytes, got {}",
+                expected_len,
+                bytes.len()
+            ));
+        }
+
+        let mut text_buffer = TextBuffer::create(terminal, (width, height))?;
+        for (idx, character) in text_buffer.chars.iter_mut().enumerate() {
+            let start = BYTES_HEADER_LEN + idx * BYTES_CHARACTER_LEN;
+            let read_f32 = |offset: usize| f32::from_bits(read_u32(start + 2 + offset * 4));
+
+            character.character = u16::from_le_bytes([bytes[start], bytes[start + 1]]);
+            character.style = TextStyle {
+                fg_color: [read_f32(0), read_f32(1), read_f32(2), read_f32(3)],
+                bg_color: [read_f32(4), read_f32(5), read_f32(6), read_f32(7)],
+                shakiness: read_f32(8),
+            };
+        }
+        Ok(text_buffer)
+    }
+
+    /// Returns the characters (and their positions) in this TextBuffer that differ from the characters in `other`.
+    ///
+    /// Returns an error if the dimensions of the TextBuffers differ.
+    pub fn diff(&self, other: &TextBuffer) -> Result<Vec<(u32, u32, TermCharacter)>, String> {
+        if self.get_dimensions() != other.get_dimensions() {
+            return Err(format!(
+                "TextBuffer dimensions differ: {:?} and {:?}",
+                self.get_dimensions(),
+                other.get_dimensions()
+            ));
+        }
+        Ok(self
+            .chars
+            .iter()
+            .zip(other.chars.iter())
+            .enumerate()
+            .filter(|(_, (character, other_character))| character != other_character)
+            .map(|(idx, (character, _))| {
+                let idx = idx as u32;
+                (idx % self.width, idx / self.width, *character)
+            })
+            .collect())
+    }
+
     /// Clears the screen (makes every character empty and resets their style)
+    ///
+    /// If padding is set with [`set_padding`](#method.set_padding), only the area inside the padding is cleared.
     pub fn clear(&mut self) {
-        self.chars = vec![
-            TermCharacter::new(' ' as u16, Default::default());
-            (self.width * self.height) as usize
-        ];
+        let (left, top, right, bottom) = self.cursor.limits.padding;
+        let empty = TermCharacter::new(' ' as u16, Default::default());
+        for y in top..self.height.saturating_sub(bottom) {
+            for x in left..self.width.saturating_sub(right) {
+                self.chars[(y * self.width + x) as usize] = empty;
+            }
+        }
+    }
+
+    /// Sets the padding (left, top, right, bottom) of the TextBuffer, in characters. Default is no padding.
+    ///
+    /// The cursor can not be moved into the padding, even with [`TermCursor::set_limits`](struct.TermCursor.html#method.set_limits),
+    /// and [`clear`](#method.clear) only clears the area inside it. Useful for keeping the contents away from the edges of the screen.
+    /// The cursor is moved inside the padding if necessary.
+    pub fn set_padding(&mut self, left: u32, top: u32, right: u32, bottom: u32) {
+        self.cursor.limits.padding = (left, top, right, bottom);
+        let (x, y) = (self.cursor.x, self.cursor.y);
+        self.cursor.move_to(x, y);
+    }
+
+    /// Returns the padding (left, top, right, bottom) of the TextBuffer (see [`set_padding`](#method.set_padding))
+    pub fn get_padding(&self) -> (u32, u32, u32, u32) {
+        self.cursor.limits.padding
+    }
+
+    /// Multiplies the foreground and background colors of every character in the given area (position, size) by `factor`.
+    ///
+    /// The parts of the area that are outside of the TextBuffer are ignored.
+    pub fn tint_region(&mut self, pos: (u32, u32), size: (u32, u32), factor: Color) {
+        let x_end = pos.0.saturating_add(size.0).min(self.width);
+        let y_end = pos.1.saturating_add(size.1).min(self.height);
+        for y in pos.1..y_end {
+            for x in pos.0..x_end {
+                let style = &mut self.chars[(y * self.width + x) as usize].style;
+                for (idx, factor) in factor.iter().enumerate() {
+                    style.fg_color[idx] = (style.fg_color[idx] * factor).clamp(0.0, 1.0);
+                    style.bg_color[idx] = (style.bg_color[idx] * factor).clamp(0.0, 1.0);
+                }
+            }
+        }
+        if x_end > pos.0 && y_end > pos.1 {
+            self.mark_dirty((pos.0, pos.1), (x_end - 1, y_end - 1));
+        }
+    }
+
+    /// Fills the given area (position, size) with a checkerboard pattern of the two characters, starting with `a` in the top left corner.
+    ///
+    /// The parts of the area that are outside of the TextBuffer are ignored.
+    pub fn fill_pattern(
+        &mut self,
+        pos: (u32, u32),
+        size: (u32, u32),
+        a: TermCharacter,
+        b: TermCharacter,
+    ) {
+        let x_end = pos.0.saturating_add(size.0).min(self.width);
+        let y_end = pos.1.saturating_add(size.1).min(self.height);
+        for y in pos.1..y_end {
+            for x in pos.0..x_end {
+                self.chars[(y * self.width + x) as usize] = match (x - pos.0 + y - pos.1) % 2 {
+                    0 => a,
+                    _ => b,
+                };
+            }
+        }
+        if x_end > pos.0 && y_end > pos.1 {
+            self.mark_dirty((pos.0, pos.1), (x_end - 1, y_end - 1));
+        }
+    }
+
+    /// Moves the characters of row `y` horizontally by `by` cells, to the right if positive and to the left if negative.
+    ///
+    /// If `wrap` is true, characters moved over one edge appear on the other edge, otherwise they are discarded
+    /// and the vacated cells are filled with empty characters. Useful for scrolling tickers.
+    /// Does nothing if `y` is out of bounds.
+    pub fn shift_row(&mut self, y: u32, by: i32, wrap: bool) {
+        if y >= self.height {
+            return;
+        }
+        let indices: Vec<usize> = (0..self.width)
+            .map(|x| (y * self.width + x) as usize)
+            .collect();
+        self.shift_cells(&indices, by, wrap);
+        self.mark_dirty((0, y), (self.width - 1, y));
+    }
+
+    /// Moves the characters of column `x` vertically by `by` cells, down if positive and up if negative.
+    ///
+    /// If `wrap` is true, characters moved over one edge appear on the other edge, otherwise they are discarded
+    /// and the vacated cells are filled with empty characters.
+    /// Does nothing if `x` is out of bounds.
+    pub fn shift_column(&mut self, x: u32, by: i32, wrap: bool) {
+        if x >= self.width {
+            return;
+        }
+        let indices: Vec<usize> = (0..self.height)
+            .map(|y| (y * self.width + x) as usize)
+            .collect();
+        self.shift_cells(&indices, by, wrap);
+        self.mark_dirty((x, 0), (x, self.height - 1));
+    }
+
+    /// Draws a horizontal or vertical line from `start` to `end` (both inclusive) with the cursor's style, using the given `BorderChars`.
+    ///
+    /// Where the line crosses or touches other lines drawn with the same `BorderChars`, the correct corner or split character is picked,
+    /// so that the lines join visually. The cursor is not moved.
+    ///
+    /// Returns an error if the line is diagonal or out of bounds.
+    ///
+    /// ```
+    /// # use glerminal::{TerminalBuilder, TextBuffer};
+    /// use glerminal::BorderChars;
+    /// # let terminal = TerminalBuilder::new().with_headless(true).build();
+    /// # let mut text_buffer = TextBuffer::create(&terminal, (3, 3)).unwrap();
+    ///
+    /// let border_chars = BorderChars::default();
+    /// text_buffer.draw_line((0, 1), (2, 1), &border_chars).unwrap();
+    /// text_buffer.draw_line((1, 0), (1, 2), &border_chars).unwrap();
+    /// // ═╬═ in the middle row
+    /// assert_eq!(text_buffer.get_character(1, 1).unwrap().get_char(), '╬');
+    /// ```
+    pub fn draw_line(
+        &mut self,
+        start: (u32, u32),
+        end: (u32, u32),
+        border_chars: &BorderChars,
+    ) -> Result<(), String> {
+        if start.0 != end.0 && start.1 != end.1 {
+            return Err("Only horizontal and vertical lines can be drawn".to_owned());
+        }
+        let min = (start.0.min(end.0), start.1.min(end.1));
+        let max = (start.0.max(end.0), start.1.max(end.1));
+        if max.0 >= self.width || max.1 >= self.height {
+            return Err(format!(
+                "Line from {:?} to {:?} is out of bounds",
+                start, end
+            ));
+        }
+
+        let horizontal = start.1 == end.1 && start.0 != end.0;
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                let idx = (y * self.width + x) as usize;
+                let existing = self.border_connections(x, y, border_chars);
+                let line = if horizontal {
+                    BorderConnections {
+                        left: x > min.0,
+                        right: x < max.0,
+                        ..Default::default()
+                    }
+                } else {
+                    BorderConnections {
+                        up: y > min.1,
+                        down: y < max.1,
+                        ..Default::default()
+                    }
+                };
+                let character = existing.join(line).to_char(border_chars, horizontal);
+
+                let mut raw = [0; 2];
+                character.encode_utf16(&mut raw);
+                self.chars[idx] = TermCharacter::new(raw[0], self.cursor.style);
+            }
+        }
+        self.mark_dirty(min, max);
+
+        Ok(())
+    }
+
+    /// Returns the directions the box-drawing character at the given position connects to,
+    /// only counting the directions where the neighboring character connects back.
+    fn border_connections(&self, x: u32, y: u32, border_chars: &BorderChars) -> BorderConnections {
+        let connections_at = |x: u32, y: u32| match self.get_character(x, y) {
+            Some(character) => BorderConnections::from_char(character.get_char(), border_chars),
+            None => BorderConnections::default(),
+        };
+        let own = connections_at(x, y);
+        BorderConnections::new(
+            own.up && y > 0 && connections_at(x, y - 1).down,
+            own.down && connections_at(x, y + 1).up,
+            own.left && x > 0 && connections_at(x - 1, y).right,
+            own.right && connections_at(x + 1, y).left,
+        )
     }
 
     /// Puts a regular character to the current position of the cursor with the cursor's style
@@ -252,16 +736,39 @@ impl TextBuffer {
     }
 
     /// Puts a raw 16-bit character to the current position of the cursor with the cursor's style (See text_buffer.cursor)
+    ///
+    /// Wide characters (characters whose glyph is about twice as wide as the average in the font, ie. CJK characters) take up two cells:
+    /// the cell after the character is left empty and the cursor is moved by two.
     pub fn put_raw_char(&mut self, character: RawCharacter) {
-        let termchar = self.chars[(self.cursor.y * self.width + self.cursor.x) as usize];
-        if termchar.character != character || termchar.style != self.cursor.style {
-            self.chars[(self.cursor.y * self.width + self.cursor.x) as usize] =
-                TermCharacter::new(character, self.cursor.style);
-            self.dirty = true;
+        let wide =
+            self.wide_chars.contains(&character) && self.cursor.x < self.cursor.limits.get_max_x();
+        self.put_raw_char_at_cursor(character);
+        if wide {
+            self.put_raw_char_at_cursor(' ' as RawCharacter);
         }
+    }
+
+    fn put_raw_char_at_cursor(&mut self, character: RawCharacter) {
+        let (x, y, style) = (self.cursor.x, self.cursor.y, self.cursor.style);
+        self.put_raw_char_at(x, y, character, style);
         self.cursor.move_by(1);
     }
 
+    /// Puts a raw 16-bit character with the given style to the given position, without using or moving the cursor.
+    ///
+    /// Positions outside the `TextBuffer` are ignored. The cell is only marked dirty if it changes.
+    pub fn put_raw_char_at(&mut self, x: u32, y: u32, character: RawCharacter, style: TextStyle) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let idx = (y * self.width + x) as usize;
+        let termchar = self.chars[idx];
+        if termchar.character != character || termchar.style != style {
+            self.chars[idx] = TermCharacter::new(character, style);
+            self.mark_dirty((x, y), (x, y));
+        }
+    }
+
     /// Puts the given text the same way as put_char
     pub fn write<T: Into<String>>(&mut self, text: T) {
         let text = text.into();
@@ -270,13 +777,34 @@ impl TextBuffer {
         }
     }
 
+    /// Writes the text centered horizontally on the given row, with the cursor's style.
+    ///
+    /// Text longer than the width of the `TextBuffer` is cut off.
+    pub fn write_centered<T: Into<String>>(&mut self, y: u32, text: T) {
+        self.write_aligned(y, text.into(), |width, len| (width - len) / 2);
+    }
+
+    /// Writes the text aligned to the right edge on the given row, with the cursor's style.
+    ///
+    /// Text longer than the width of the `TextBuffer` is cut off.
+    pub fn write_right_aligned<T: Into<String>>(&mut self, y: u32, text: T) {
+        self.write_aligned(y, text.into(), |width, len| width - len);
+    }
+
+    fn write_aligned<F: Fn(u32, u32) -> u32>(&mut self, y: u32, text: String, start_x: F) {
+        let text: Vec<RawCharacter> = text.encode_utf16().take(self.width as usize).collect();
+        self.cursor
+            .move_to(start_x(self.width, text.len() as u32), y);
+        for c in text {
+            self.put_raw_char(c);
+        }
+    }
+
     /// Write a list of [`ProcessedChar`](text_processing/struct.ProcessedChar.html)s
     pub fn write_processed(&mut self, char_list: &[ProcessedChar]) {
         let default = self.cursor.style;
         for character in char_list {
-            self.cursor.style.fg_color = character.style.fg_color.unwrap_or(default.fg_color);
-            self.cursor.style.bg_color = character.style.bg_color.unwrap_or(default.bg_color);
-            self.cursor.style.shakiness = character.style.shakiness.unwrap_or(default.shakiness);
+            self.cursor.style = character.style.apply_to(default);
             self.put_char(character.character);
         }
         self.cursor.style = default;
@@ -287,14 +815,223 @@ impl TextBuffer {
         (self.cursor.x, self.cursor.y)
     }
 
+    /// Saves the position, style and limits of the cursor on top of a stack, so they can be restored with [`restore_cursor`](#method.restore_cursor).
+    ///
+    /// Useful for drawing nested components that change the cursor.
+    pub fn save_cursor(&mut self) {
+        self.cursor_stack.push(self.cursor.clone());
+    }
+
+    /// Restores the position, style and limits of the cursor that were last saved with [`save_cursor`](#method.save_cursor).
+    ///
+    /// The current padding (see [`set_padding`](#method.set_padding)) is kept, and the restored cursor is moved inside it if necessary.
+    ///
+    /// Returns false and leaves the cursor as is if there was no saved cursor.
+    pub fn restore_cursor(&mut self) -> bool {
+        match self.cursor_stack.pop() {
+            Some(cursor) => {
+                let padding = self.cursor.limits.padding;
+                self.cursor = cursor;
+                self.cursor.limits.padding = padding;
+                let (x, y) = (self.cursor.x, self.cursor.y);
+                self.cursor.move_to(x, y);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Returns whether the TextBuffer is dirty or not (whether flush will have any effect or not)
     pub fn is_dirty(&self) -> bool {
         self.dirty
     }
+
+    /// Returns the bounding box `(min_x, min_y, max_x, max_y)` of the characters that have changed since the last flush,
+    /// or None if nothing has changed.
+    pub fn dirty_bounds(&self) -> Option<(u32, u32, u32, u32)> {
+        self.dirty_bounds
+    }
+
+    /// Moves the characters in the given indices forward by `by` positions
+    fn shift_cells(&mut self, indices: &[usize], by: i32, wrap: bool) {
+        let len = indices.len() as i32;
+        let original: Vec<TermCharacter> = indices.iter().map(|idx| self.chars[*idx]).collect();
+        for (pos, idx) in indices.iter().enumerate() {
+            let source = pos as i32 - by;
+            self.chars[*idx] = if wrap {
+                original[source.rem_euclid(len) as usize]
+            } else if source >= 0 && source < len {
+                original[source as usize]
+            } else {
+                TermCharacter::new(' ' as u16, Default::default())
+            };
+        }
+    }
+
+    fn modify_style<F: FnOnce(&mut TextStyle)>(&mut self, x: u32, y: u32, f: F) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        f(&mut self.chars[(y * self.width + x) as usize].style);
+        self.mark_dirty((x, y), (x, y));
+    }
+
+    fn mark_dirty(&mut self, min: (u32, u32), max: (u32, u32)) {
+        self.dirty = true;
+        self.dirty_bounds = Some(match self.dirty_bounds {
+            Some((min_x, min_y, max_x, max_y)) => (
+                min_x.min(min.0),
+                min_y.min(min.1),
+                max_x.max(max.0),
+                max_y.max(max.1),
+            ),
+            None => (min.0, min.1, max.0, max.1),
+        });
+    }
+}
+
+/// Which directions a box-drawing character connects to
+#[derive(Clone, Copy, Default, PartialEq)]
+struct BorderConnections {
+    up: bool,
+    down: bool,
+    left: bool,
+    right: bool,
+}
+
+impl BorderConnections {
+    fn new(up: bool, down: bool, left: bool, right: bool) -> BorderConnections {
+        BorderConnections {
+            up,
+            down,
+            left,
+            right,
+        }
+    }
+
+    fn from_char(character: char, border_chars: &BorderChars) -> BorderConnections {
+        match character {
+            c if c == border_chars.horizontal_line => {
+                BorderConnections::new(false, false, true, true)
+            }
+            c if c == border_chars.vertical_line => {
+                BorderConnections::new(true, true, false, false)
+            }
+            c if c == border_chars.top_left => BorderConnections::new(false, true, false, true),
+            c if c == border_chars.top_right => BorderConnections::new(false, true, true, false),
+            c if c == border_chars.bottom_left => BorderConnections::new(true, false, false, true),
+            c if c == border_chars.bottom_right => BorderConnections::new(true, false, true, false),
+            c if c == border_chars.top_split => BorderConnections::new(false, true, true, true),
+            c if c == border_chars.bottom_split => BorderConnections::new(true, false, true, true),
+            c if c == border_chars.left_split => BorderConnections::new(true, true, false, true),
+            c if c == border_chars.right_split => BorderConnections::new(true, true, true, false),
+            c if c == border_chars.middle_split => BorderConnections::new(true, true, true, true),
+            _ => BorderConnections::default(),
+        }
+    }
+
+    fn join(self, other: BorderConnections) -> BorderConnections {
+        BorderConnections::new(
+            self.up || other.up,
+            self.down || other.down,
+            self.left || other.left,
+            self.right || other.right,
+        )
+    }
+
+    fn to_char(self, border_chars: &BorderChars, horizontal: bool) -> char {
+        match (self.up, self.down, self.left, self.right) {
+            (true, true, true, true) => border_chars.middle_split,
+            (false, true, true, true) => border_chars.top_split,
+            (true, false, true, true) => border_chars.bottom_split,
+            (true, true, false, true) => border_chars.left_split,
+            (true, true, true, false) => border_chars.right_split,
+            (false, true, false, true) => border_chars.top_left,
+            (false, true, true, false) => border_chars.top_right,
+            (true, false, false, true) => border_chars.bottom_left,
+            (true, false, true, false) => border_chars.bottom_right,
+            (false, false, _, _) if self.left || self.right || horizontal => {
+                border_chars.horizontal_line
+            }
+            _ => border_chars.vertical_line,
+        }
+    }
+}
+
+/// Represents all the different characters that are used in drawing borders and lines,
+/// for example by [`TextBuffer::draw_line`](struct.TextBuffer.html#method.draw_line) and `menu_systems::Window`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BorderChars {
+    /// The top left corner piece. Default is ╔
+    pub top_left: char,
+    /// The bottom left corner piece. Default is ╚
+    pub bottom_left: char,
+    /// The top right corner piece. Default is ╗
+    pub top_right: char,
+    /// The bottom right corner piece. Default is ╝
+    pub bottom_right: char,
+
+    /// A vertical line for non-corner borders and splits. Default is ║
+    pub vertical_line: char,
+    /// A horizontal line for non-corner borders and splits. Default is ═
+    pub horizontal_line: char,
+
+    /// The topmost character for a horizontal split. Default is ╦
+    pub top_split: char,
+    /// The bottom-most character for a horizontal split. Default is ╩
+    pub bottom_split: char,
+    /// The leftmost-most character for a vertial split. Default is ╠
+    pub left_split: char,
+    /// The rightmost-most character for a vertial split. Default is ╣
+    pub right_split: char,
+    /// The middle character where two splits intersect. Default is ╬
+    pub middle_split: char,
+}
+
+impl BorderChars {
+    /// Make BorderChars where every field is a spacebar.
+    pub fn empty() -> BorderChars {
+        BorderChars {
+            top_left: ' ',
+            bottom_left: ' ',
+            top_right: ' ',
+            bottom_right: ' ',
+
+            vertical_line: ' ',
+            horizontal_line: ' ',
+
+            top_split: ' ',
+            bottom_split: ' ',
+            left_split: ' ',
+            right_split: ' ',
+            middle_split: ' ',
+        }
+    }
+}
+
+impl Default for BorderChars {
+    fn default() -> BorderChars {
+        BorderChars {
+            top_left: '╔',
+            bottom_left: '╚',
+            top_right: '╗',
+            bottom_right: '╝',
+
+            vertical_line: '║',
+            horizontal_line: '═',
+
+            top_split: '╦',
+            bottom_split: '╩',
+            left_split: '╠',
+            right_split: '╣',
+            middle_split: '╬',
+        }
+    }
 }
 
 /// Represents a style that can be used to style text.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextStyle {
     /// Foreground color of the text
     pub fg_color: Color,
@@ -314,8 +1051,46 @@ impl Default for TextStyle {
     }
 }
 
+impl TextStyle {
+    /// Black text on a white background, the opposite of the default style.
+    pub fn inverted() -> TextStyle {
+        TextStyle {
+            fg_color: [0.0, 0.0, 0.0, 1.0],
+            bg_color: [1.0; 4],
+            shakiness: 0.0,
+        }
+    }
+
+    /// Red text, for errors.
+    ///
+    /// ```
+    /// use glerminal::{TerminalBuilder, TextBuffer, TextStyle};
+    ///
+    /// let terminal = TerminalBuilder::new().with_headless(true).build();
+    /// let mut text_buffer = TextBuffer::create(&terminal, (80, 24)).unwrap();
+    ///
+    /// text_buffer.cursor.style = TextStyle::error();
+    /// text_buffer.write("Something went wrong!");
+    /// ```
+    pub fn error() -> TextStyle {
+        TextStyle {
+            fg_color: [0.9, 0.2, 0.2, 1.0],
+            ..Default::default()
+        }
+    }
+
+    /// Yellow text, for warnings.
+    pub fn warning() -> TextStyle {
+        TextStyle {
+            fg_color: [0.95, 0.8, 0.2, 1.0],
+            ..Default::default()
+        }
+    }
+}
+
 /// Represents a single character in a [`TextBuffer`](struct.TextBuffer.html)
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TermCharacter {
     character: RawCharacter,
     /// The styling of this character
@@ -336,6 +1111,16 @@ impl TermCharacter {
     pub fn get_char(&self) -> char {
         String::from_utf16(&[self.character]).unwrap().remove(0)
     }
+
+    /// Returns whether the character is a space, meaning no glyph is drawn for it
+    pub fn is_space(&self) -> bool {
+        self.character == ' ' as RawCharacter
+    }
+
+    /// Returns whether the background color of the character is visible, ie. it's alpha is above zero
+    pub fn has_visible_background(&self) -> bool {
+        self.style.bg_color[3] > 0.0
+    }
 }
 
 /// The cursor on the TextBuffer that you can move around and change it's style.  
@@ -400,6 +1185,8 @@ pub struct TermLimits {
     x_max: Option<u32>,
     y_min: Option<u32>,
     y_max: Option<u32>,
+    // Padding of the TextBuffer (left, top, right, bottom)
+    padding: (u32, u32, u32, u32),
 }
 
 impl TermLimits {
@@ -411,42 +1198,47 @@ impl TermLimits {
             x_max: None,
             y_min: None,
             y_max: None,
+            padding: (0, 0, 0, 0),
         }
     }
 
     /// Represents the smallest x-coordinate you should be able to write to (e.g. 0).
     pub fn get_min_x(&self) -> u32 {
+        let min_x = self.padding.0;
         if let Some(x_min) = self.x_min {
-            x_min.max(0)
+            x_min.max(min_x)
         } else {
-            0
+            min_x
         }
     }
 
     /// Represents the largest x-coordinate, where you should be able to write. (e.g. screen width - 1)
     pub fn get_max_x(&self) -> u32 {
+        let max_x = self.width.saturating_sub(self.padding.2.saturating_add(1));
         if let Some(x_max) = self.x_max {
-            x_max.min(self.width - 1)
+            x_max.min(max_x)
         } else {
-            self.width - 1
+            max_x
         }
     }
 
     /// Represents the smallest y-coordinate, where you should be able to write to (e.g. 0).
     pub fn get_min_y(&self) -> u32 {
+        let min_y = self.padding.1;
         if let Some(y_min) = self.y_min {
-            y_min.max(0)
+            y_min.max(min_y)
         } else {
-            0
+            min_y
         }
     }
 
     /// Represents the largest y-coordinate, where you should be able to write. (e.g. screen height - 1)
     pub fn get_max_y(&self) -> u32 {
+        let max_y = self.height.saturating_sub(self.padding.3.saturating_add(1));
         if let Some(y_max) = self.y_max {
-            y_max.min(self.height - 1)
+            y_max.min(max_y)
         } else {
-            self.height - 1
+            max_y
         }
     }
 }
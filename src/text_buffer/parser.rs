@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use super::{Color, TextBuffer};
-use regex::Regex;
+use regex::{Captures, NoExpand, Regex};
 
 use crate::text_processing::{OptTextStyle, Processable, ProcessedChar, TextProcessor};
 
@@ -33,14 +33,33 @@ use crate::text_processing::{OptTextStyle, Processable, ProcessedChar, TextProce
 /// parser.write(&mut text_buffer, "Hello, [fg=red]this color is red![/fg], [bg=red]this text has a red background[/bg] and [shake=0.5]this text shakes[/shake]");
 /// // Note: it is not necessary to close fg/bg/shake tags, parser will automatically revert colors in the TextBuffer.
 ///
+/// // Variables are replaced with their values before styling, so the values can contain tags and other variables
+/// parser.add_var("player_name", "[fg=red]Alice[/fg]");
+/// parser.write(&mut text_buffer, "Hello, [var=player_name]!");
+///
 /// // Flush to "apply changes"
 /// terminal.flush(&mut text_buffer);
 /// ```
 ///
 /// See [TextBuffer](struct.TextBuffer.html) for examples and more detailed documentation.
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct Parser {
     colors: HashMap<String, Color>,
+    vars: HashMap<String, String>,
+    var_placeholder: String,
+    var_regex: Regex,
+}
+
+/// How many times variables are expanded inside the values of other variables, to prevent infinite recursion
+const MAX_VAR_DEPTH: usize = 8;
+/// The maximum length (in bytes) a text can grow to by expanding variables,
+/// so variables that refer to themselves several times can not grow the text geometrically
+pub(crate) const MAX_VAR_EXPANDED_LEN: usize = 64 * 1024;
+
+impl Default for Parser {
+    fn default() -> Parser {
+        Parser::new()
+    }
 }
 
 impl Parser {
@@ -48,6 +67,9 @@ impl Parser {
     pub fn new() -> Parser {
         Parser {
             colors: HashMap::<String, Color>::new(),
+            vars: HashMap::new(),
+            var_placeholder: String::new(),
+            var_regex: Regex::new(r"\[var=([A-Za-z0-9_]+)\]").unwrap(),
         }
     }
 
@@ -56,6 +78,18 @@ impl Parser {
         self.colors.insert(color_str.into(), color);
     }
 
+    /// Adds a variable to the parser, so that `[var=name]` in the text is replaced with `value`.
+    ///
+    /// The value can contain tags and other variables, which are processed after the replacement.
+    pub fn add_var<T: Into<String>, U: Into<String>>(&mut self, name: T, value: U) {
+        self.vars.insert(name.into(), value.into());
+    }
+
+    /// Sets the text that undefined variables are replaced with. Default is an empty text.
+    pub fn set_var_placeholder<T: Into<String>>(&mut self, placeholder: T) {
+        self.var_placeholder = placeholder.into();
+    }
+
     /// Parses the given text and immediately writes it to the text buffer
     pub fn write<T: Into<String>>(&self, text_buffer: &mut TextBuffer, text: T) {
         text_buffer.write_processed(&self.parse(text));
@@ -68,6 +102,51 @@ impl Parser {
         self.process(vec![text.into()])
     }
 
+    /// Returns the amount of characters the text has when parsed, ie. the length of the text without the tags.
+    ///
+    /// Useful for laying out parsed text, for example: `parser.visible_len("[fg=red]abc[/fg]")` is 3.
+    pub fn visible_len(&self, text: &str) -> usize {
+        self.parse(text).len()
+    }
+
+    /// Parses the text and returns the visible text (without the tags) of each line, split at newlines.
+    ///
+    /// Useful for measuring and wrapping parsed text.
+    pub fn visible_lines(&self, text: &str) -> Vec<String> {
+        let visible: String = self.parse(text).iter().map(|c| c.character).collect();
+        visible.split('\n').map(|line| line.to_owned()).collect()
+    }
+
+    /// Replaces the variables in the text with their values, including variables inside the values.
+    ///
+    /// Variables that would grow the text past `MAX_VAR_EXPANDED_LEN` are treated as undefined.
+    fn expand_vars(&self, text: &str) -> String {
+        let regex = &self.var_regex;
+        let mut text = text.to_owned();
+        for _ in 0..MAX_VAR_DEPTH {
+            if !regex.is_match(&text) {
+                break;
+            }
+            let mut len = text.len();
+            text = regex
+                .replace_all(&text, |captures: &Captures| {
+                    let value = self.vars.get(&captures[1]).unwrap_or(&self.var_placeholder);
+                    let value = if len - captures[0].len() + value.len() > MAX_VAR_EXPANDED_LEN {
+                        &self.var_placeholder
+                    } else {
+                        value
+                    };
+                    len = len - captures[0].len() + value.len();
+                    value.clone()
+                })
+                .into_owned();
+        }
+        // Any variables still left are too deep, so they are treated as undefined
+        regex
+            .replace_all(&text, NoExpand(&self.var_placeholder))
+            .into_owned()
+    }
+
     /// Gets the color specified, not compiled in a non-testing environment.
     #[cfg(test)]
     pub(crate) fn get_color(&self, color: &str) -> Option<&Color> {
@@ -93,6 +172,7 @@ impl TextProcessor for Parser {
         for processable in processables {
             match processable {
                 Processable::ToProcess(text) => {
+                    let text = self.expand_vars(&text);
                     let mut parts = regex.split(&text);
                     for capture in regex.captures_iter(&text) {
                         parsed.push(ParsedText {
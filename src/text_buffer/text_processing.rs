@@ -3,7 +3,7 @@
 //!
 //! Using the `DefaultProcessor` without intent on using any other TextProcessors, is very inefficient compared to just using `write`.
 
-use crate::Color;
+use crate::{Color, TextStyle};
 
 /// The default processor that does nothing else, but take the text and apply the given style to each of those `char`s
 #[derive(Debug, Clone, Copy)]
@@ -33,6 +33,90 @@ impl TextProcessor for DefaultProcessor {
     }
 }
 
+/// A processor like the `DefaultProcessor`, but which treats line breaks as row breaks instead of glyphs.
+///
+/// Every line break (`\n`, `\r\n` or `\r`) in the text is output as a single `'\n'` `ProcessedChar`, which acts as a sentinel
+/// between the rows. The rows can be retrieved with [`process_lines`](#method.process_lines) or [`split_lines`](fn.split_lines.html).
+///
+/// ```
+/// use glerminal::text_processing::{strip, LineProcessor};
+///
+/// let rows = LineProcessor.process_lines(vec!["first\r\nsecond".into()]);
+/// assert_eq!(rows.len(), 2);
+/// assert_eq!(strip(&rows[1]), "second");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LineProcessor;
+
+impl LineProcessor {
+    /// Processes the given processables and splits the result into rows at the line breaks.
+    pub fn process_lines(&self, processables: Vec<Processable>) -> Vec<Vec<ProcessedChar>> {
+        split_lines(&self.process(processables))
+    }
+}
+
+impl TextProcessor for LineProcessor {
+    fn process(&self, processables: Vec<Processable>) -> Vec<ProcessedChar> {
+        let mut list = Vec::new();
+        for processable in processables {
+            let text = match processable {
+                Processable::ToProcess(text) => text,
+                Processable::NoProcess(text) => text,
+            };
+            let mut chars = text.chars().peekable();
+            while let Some(c) = chars.next() {
+                let character = match c {
+                    '\r' => {
+                        if chars.peek() == Some(&'\n') {
+                            chars.next();
+                        }
+                        '\n'
+                    }
+                    _ => c,
+                };
+                list.push(ProcessedChar::new(character, OptTextStyle::default()));
+            }
+        }
+        list
+    }
+}
+
+/// A `TextProcessor` that runs multiple processors one after another.
+///
+/// The processables are given to the first processor, and the output of each processor is then given to the next one
+/// as [`Processable::NoProcess`](enum.Processable.html#variant.NoProcess), so the output of the last processor is the output of the `Chain`.
+/// If a processor outputs as many characters as it was given, the style of each character is inherited from the previous processor's output,
+/// unless the processor sets the style itself. This means that a processor that styles text (like the Parser) should be the first one in the chain.
+///
+/// ```
+/// use glerminal::text_processing::{Chain, DefaultProcessor, TextProcessor};
+///
+/// let chain = Chain(vec![Box::new(DefaultProcessor), Box::new(DefaultProcessor)]);
+/// assert_eq!(chain.process(vec!["abc".into()]).len(), 3);
+/// ```
+pub struct Chain(pub Vec<Box<dyn TextProcessor>>);
+
+impl TextProcessor for Chain {
+    fn process(&self, processables: Vec<Processable>) -> Vec<ProcessedChar> {
+        let mut processors = self.0.iter();
+        let mut processed = match processors.next() {
+            Some(processor) => processor.process(processables),
+            None => return DefaultProcessor.process(processables),
+        };
+        for processor in processors {
+            let text = strip(&processed);
+            let mut next = processor.process(vec![Processable::NoProcess(text)]);
+            if next.len() == processed.len() {
+                for (next_char, previous) in next.iter_mut().zip(&processed) {
+                    next_char.style = next_char.style.or(&previous.style);
+                }
+            }
+            processed = next;
+        }
+        processed
+    }
+}
+
 /// A string that can be given for a TextProcessor.
 pub enum Processable {
     /// A String that will be processed when given to a processor
@@ -67,17 +151,96 @@ pub trait TextProcessor {
     fn process(&self, processables: Vec<Processable>) -> Vec<ProcessedChar>;
 }
 
+/// Returns only the characters of the given `ProcessedChar`s, dropping their styles.
+///
+/// Useful for exporting processed text as plain text.
+pub fn strip(processed: &[ProcessedChar]) -> String {
+    processed.iter().map(|c| c.character).collect()
+}
+
+/// Splits the given `ProcessedChar`s into rows at every `'\n'`, dropping the line breaks themselves.
+///
+/// Text without line breaks results in a single row.
+pub fn split_lines(processed: &[ProcessedChar]) -> Vec<Vec<ProcessedChar>> {
+    processed
+        .split(|c| c.character == '\n')
+        .map(|row| row.to_vec())
+        .collect()
+}
+
+/// Processes the given text with the processor, as if it was given as a single [`Processable::ToProcess`](enum.Processable.html#variant.ToProcess).
+///
+/// Useful for testing custom processors:
+/// ```
+/// use glerminal::text_processing::{test_process, DefaultProcessor};
+///
+/// let processed = test_process(&DefaultProcessor, "abc");
+/// assert_eq!(processed.len(), 3);
+/// assert_eq!(processed[0].character, 'a');
+/// assert_eq!(processed[0].style.fg_color, None);
+/// ```
+pub fn test_process(processor: &dyn TextProcessor, input: &str) -> Vec<ProcessedChar> {
+    processor.process(vec![Processable::ToProcess(input.to_owned())])
+}
+
+/// Returns the given `ProcessedChar`s with their styles reset, so that they are written with the style of the `TextBuffer`'s cursor.
+pub fn default_style(processed: &[ProcessedChar]) -> Vec<ProcessedChar> {
+    processed
+        .iter()
+        .map(|c| ProcessedChar {
+            character: c.character,
+            style: OptTextStyle {
+                fg_color: None,
+                bg_color: None,
+                shakiness: None,
+            },
+        })
+        .collect()
+}
+
 /// A `char` that has been processed by a `TextProcessor`. Contains the `char` and it's style
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ProcessedChar {
     /// The character
     pub character: char,
-    pub(crate) style: OptTextStyle,
+    /// The style of the character. The fields that are not set are taken from the style of the `TextBuffer`'s cursor when written.
+    pub style: OptTextStyle,
 }
 
-#[derive(Debug, Clone)]
-pub(crate) struct OptTextStyle {
+impl ProcessedChar {
+    /// Creates a new ProcessedChar with the given character and style
+    pub fn new(character: char, style: OptTextStyle) -> ProcessedChar {
+        ProcessedChar { character, style }
+    }
+}
+
+/// A style where every field is optional, used by `ProcessedChar`s. Default is a style with no fields set.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OptTextStyle {
+    /// Foreground color of the text, if set
     pub fg_color: Option<Color>,
+    /// Background color of the text, if set
     pub bg_color: Option<Color>,
+    /// "Shakiness" of the text, if set
     pub shakiness: Option<f32>,
 }
+
+impl OptTextStyle {
+    /// Returns the given style, with the fields that are set in this style replaced.
+    pub fn apply_to(&self, style: TextStyle) -> TextStyle {
+        TextStyle {
+            fg_color: self.fg_color.unwrap_or(style.fg_color),
+            bg_color: self.bg_color.unwrap_or(style.bg_color),
+            shakiness: self.shakiness.unwrap_or(style.shakiness),
+        }
+    }
+
+    /// Returns this style, with the unset fields taken from `other`
+    pub fn or(&self, other: &OptTextStyle) -> OptTextStyle {
+        OptTextStyle {
+            fg_color: self.fg_color.or(other.fg_color),
+            bg_color: self.bg_color.or(other.bg_color),
+            shakiness: self.shakiness.or(other.shakiness),
+        }
+    }
+}